@@ -0,0 +1,73 @@
+use aoc_common::differential::Rng;
+use aoc_common::{Answer, AocError, Result, Solution};
+
+pub struct Day01 {
+    /// Total calories carried by each elf, in input order.
+    elves: Vec<i32>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &str) -> Result<Day01> {
+        let mut elves = vec![];
+        let mut m = 0;
+        for string_line in input.lines() {
+            if let Ok(parsed_line) = string_line.parse::<i32>() {
+                m += parsed_line
+            } else {
+                elves.push(m);
+                m = 0
+            }
+        }
+        elves.push(m);
+        Ok(Day01 { elves })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        self.elves
+            .iter()
+            .max()
+            .map(|&m| m.into())
+            .ok_or_else(|| AocError::parse("no elves in the input"))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut n = self.elves.clone();
+        n.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(n.iter().take(3).sum::<i32>().into())
+    }
+
+    /// Up to a hundred elves carrying a handful of snacks each.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let elves: Vec<String> = (0..rng.range(1..=100))
+            .map(|_| {
+                let snacks: Vec<String> = (0..rng.range(1..=6))
+                    .map(|_| rng.range(1..=20000).to_string())
+                    .collect();
+                snacks.join("\n")
+            })
+            .collect();
+        Ok(elves.join("\n\n"))
+    }
+
+    /// Takes the biggest load off the pile three times over.
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let mut loads = vec![];
+        for elf in input.trim_end().split("\n\n") {
+            let mut load = 0;
+            for snack in elf.lines() {
+                load += snack
+                    .parse::<i32>()
+                    .map_err(|_| AocError::parse(format!("{:?} is not a snack", snack)))?;
+            }
+            loads.push(load);
+        }
+        let mut top = vec![];
+        for _ in 0..3 {
+            match loads.iter().enumerate().max_by_key(|&(_, load)| load) {
+                Some((i, _)) => top.push(loads.remove(i)),
+                None => break,
+            }
+        }
+        Ok((top[0].into(), Some(top.iter().sum::<i32>().into())))
+    }
+}
@@ -0,0 +1,61 @@
+use aoc_common::{Answer, Result, Solution};
+
+fn parse_line(line: String) -> u32 {
+    match &*line {
+        "A X" => 4, // Rock draw
+        "A Y" => 8, // Paper win
+        "A Z" => 3, // Scissors loose
+        "B X" => 1, // Rock loose
+        "B Y" => 5, // Paper draw
+        "B Z" => 9, // Scissors win
+        "C X" => 7, // Rock win
+        "C Y" => 2, // Paper loose
+        "C Z" => 6, // Scissors draw
+        _ => 0,
+    }
+}
+
+fn parse_line_2(line: String) -> u32 {
+    match &*line {
+        "A X" => 3, // Scissors loose
+        "A Y" => 4, // Rock draw
+        "A Z" => 8, // Paper win
+        "B X" => 1, // Rock loose
+        "B Y" => 5, // Paper draw
+        "B Z" => 9, // Scissors win
+        "C X" => 2, // Paper loose
+        "C Y" => 6, // Scissors draw
+        "C Z" => 7, // Rock win
+        _ => 0,
+    }
+}
+
+pub struct Day02 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day02 {
+    fn parse(input: &str) -> Result<Day02> {
+        Ok(Day02 {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let s = self
+            .lines
+            .iter()
+            .map(|x| parse_line(x.to_string()))
+            .sum::<u32>();
+        Ok(s.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let s = self
+            .lines
+            .iter()
+            .map(|x| parse_line_2(x.to_string()))
+            .sum::<u32>();
+        Ok(s.into())
+    }
+}
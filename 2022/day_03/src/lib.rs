@@ -1,33 +1,19 @@
+use aoc_common::{Answer, Result, Solution};
 use std::collections::HashSet;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
 
 static ALPHABET: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 fn get_priority(item: &char) -> usize {
     let index = ALPHABET.chars().position(|e| &e == item).unwrap();
-    return index + 1;
+    index + 1
 }
 
-#[allow(dead_code)]
 fn parse_line(line: String) -> Vec<usize> {
     let len = line.chars().count();
     let mut first_compartment: HashSet<char> = vec![].into_iter().collect();
     let mut second_compartment: HashSet<char> = vec![].into_iter().collect();
     line.chars().enumerate().for_each(|(i, x)| {
-        if i <= len / 2 - 1 {
+        if i < len / 2 {
             first_compartment.insert(x);
         } else {
             second_compartment.insert(x);
@@ -37,12 +23,12 @@ fn parse_line(line: String) -> Vec<usize> {
     first_compartment
         .intersection(&second_compartment)
         .for_each(|x| output.push(get_priority(x)));
-    return output;
+    output
 }
 
 fn parse_triple_lines(lines: &[String]) -> usize {
     let intersection = lines
-        .into_iter()
+        .iter()
         .map(|x| x.chars().collect())
         .collect::<Vec<HashSet<char>>>()
         .into_iter()
@@ -58,18 +44,28 @@ fn parse_triple_lines(lines: &[String]) -> usize {
     }
 }
 
-fn main() {
-    if let Some(arg1) = env::args().nth(1) {
-        // File hosts must exist in current path before this produces output
-        if let Ok(lines) = read_lines(arg1) {
-            // Consumes the iterator, returns an (Optional) String
-            let s = lines
-                .filter_map(|x| x.ok())
-                .collect::<Vec<String>>()
-                .chunks(3)
-                .map(|x| parse_triple_lines(x))
-                .sum::<usize>();
-            println!("{}", s)
-        }
+pub struct Day03 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day03 {
+    fn parse(input: &str) -> Result<Day03> {
+        Ok(Day03 {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let s = self
+            .lines
+            .iter()
+            .flat_map(|x| parse_line(x.to_string()))
+            .sum::<usize>();
+        Ok(s.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let s = self.lines.chunks(3).map(parse_triple_lines).sum::<usize>();
+        Ok(s.into())
     }
 }
@@ -0,0 +1,96 @@
+use aoc_common::differential::Rng;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{Answer, IntervalSet, Result, Solution};
+use std::collections::HashSet;
+
+type Pair = (IntervalSet<i32>, IntervalSet<i32>);
+
+fn parse_line(mut line: Scanner) -> Result<Pair> {
+    let assignment = |line: &mut Scanner| -> Result<IntervalSet<i32>> {
+        let first = line.number()?;
+        line.literal("-")?;
+        let last = line.number()?;
+        if last < first {
+            return Err(line.error(format!("a section from {} on", first)));
+        }
+        Ok(IntervalSet::from(first..=last))
+    };
+    let first = assignment(&mut line)?;
+    line.literal(",")?;
+    let second = assignment(&mut line)?;
+    line.end()?;
+    Ok((first, second))
+}
+
+/// Every section one elf of a pair cleans, one by one.
+fn sections(line: &mut Scanner) -> Result<HashSet<i32>> {
+    let first = line.number()?;
+    line.literal("-")?;
+    let last = line.number()?;
+    if last < first {
+        return Err(line.error(format!("a section from {} on", first)));
+    }
+    Ok((first..=last).collect())
+}
+
+fn is_contained((first, second): &Pair) -> u32 {
+    (first.is_subset(second) || second.is_subset(first)) as u32
+}
+
+fn overlaps((first, second): &Pair) -> u32 {
+    (!first.intersection(second).is_empty()) as u32
+}
+
+pub struct Day04 {
+    pairs: Vec<Pair>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &str) -> Result<Day04> {
+        Ok(Day04 {
+            pairs: parse::lines(input)
+                .map(parse_line)
+                .collect::<Result<Vec<Pair>>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let r: u32 = self.pairs.iter().map(is_contained).sum::<u32>();
+        Ok(r.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let r: u32 = self.pairs.iter().map(overlaps).sum::<u32>();
+        Ok(r.into())
+    }
+
+    /// Up to fifty pairs of elves cleaning short runs of sections.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let mut range = || {
+            let first = rng.range(1..=99);
+            format!("{}-{}", first, rng.range(first..=(first + 20).min(99)))
+        };
+        let pairs: Vec<String> = (0..50)
+            .map(|_| format!("{},{}", range(), range()))
+            .collect();
+        Ok(pairs.join("\n"))
+    }
+
+    /// Compares the pairs section by section.
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let (mut contained, mut overlapping) = (0, 0);
+        for mut line in parse::lines(input) {
+            let first = sections(&mut line)?;
+            line.literal(",")?;
+            let second = sections(&mut line)?;
+            line.end()?;
+            if first.is_subset(&second) || second.is_subset(&first) {
+                contained += 1;
+            }
+            if !first.is_disjoint(&second) {
+                overlapping += 1;
+            }
+        }
+        Ok((contained.into(), Some(overlapping.into())))
+    }
+}
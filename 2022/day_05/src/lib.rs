@@ -0,0 +1,205 @@
+use aoc_common::differential::Rng;
+use aoc_common::parse;
+use aoc_common::{debug, Answer, AocError, Result, Solution};
+use std::collections::HashMap;
+
+const STACKS: &str = "[V]         [T]         [J]
+[Q]         [M] [P]     [Q]     [J]
+[W] [B]     [N] [Q]     [C]     [T]
+[M] [C]     [F] [N]     [G] [W] [G]
+[B] [W] [J] [H] [L]     [R] [B] [C]
+[N] [R] [R] [W] [W] [W] [D] [N] [F]
+[Z] [Z] [Q] [S] [F] [P] [B] [Q] [L]
+[C] [H] [F] [Z] [G] [L] [V] [Z] [H]
+ 1   2   3   4   5   6   7   8   9";
+
+fn create_stacks(stack_string: &str) -> HashMap<char, Vec<char>> {
+    let lines = stack_string.split("\n").collect::<Vec<&str>>();
+    let last_line = lines.last().unwrap();
+    let mut output: HashMap<char, Vec<char>> = HashMap::new();
+    let mut reversed_lines = stack_string
+        .split("\n")
+        .map(|x| x.to_string())
+        .collect::<Vec<String>>();
+    reversed_lines.pop();
+    reversed_lines.reverse();
+
+    last_line.chars().enumerate().for_each(|(i, x)| {
+        if x != ' ' {
+            output.insert(x, vec![]);
+            for line in &reversed_lines {
+                let c = line.chars().nth(i).unwrap_or(' ');
+                if c != ' ' {
+                    output.get_mut(&x).unwrap().push(c)
+                }
+            }
+        }
+    });
+
+    output
+}
+
+fn move_from_i_to_j(stacks: &mut HashMap<char, Vec<char>>, i: char, j: char) {
+    let v = stacks.get_mut(&i).unwrap().pop().unwrap();
+    stacks.get_mut(&j).unwrap().push(v)
+}
+
+fn move_block_from_i_to_j(
+    stacks: &mut HashMap<char, Vec<char>>,
+    block_size: u32,
+    i: char,
+    j: char,
+) {
+    let mut block: Vec<char> = vec![];
+    for _ in 0..block_size {
+        block.push(stacks.get_mut(&i).unwrap().pop().unwrap())
+    }
+    block.reverse();
+    for v in block {
+        stacks.get_mut(&j).unwrap().push(v)
+    }
+}
+
+fn parse_line(line: String) -> (u32, char, char) {
+    let split_line = line.split(' ').collect::<Vec<&str>>();
+    (
+        split_line.get(1).unwrap().parse::<u32>().unwrap(),
+        split_line.get(3).unwrap().chars().next().unwrap(),
+        split_line.get(5).unwrap().chars().next().unwrap(),
+    )
+}
+
+fn message(stacks: &HashMap<char, Vec<char>>) -> String {
+    let message: Vec<&char> = ['1', '2', '3', '4', '5', '6', '7', '8', '9']
+        .iter()
+        .map(|c| {
+            if stacks.get(c).is_some() {
+                stacks.get(c).unwrap().last().unwrap()
+            } else {
+                &'x'
+            }
+        })
+        .collect::<Vec<&char>>();
+    message.into_iter().collect::<String>()
+}
+
+/// The stacks in `drawing` from left to right, each from the bottom up.
+fn stacks_bottom_up(drawing: &str) -> Vec<Vec<char>> {
+    let rows: Vec<Vec<char>> = drawing.lines().map(|row| row.chars().collect()).collect();
+    let (labels, crates) = rows.split_last().expect("the drawing has a row of labels");
+    (0..labels.len())
+        .filter(|&column| labels[column].is_ascii_digit())
+        .map(|column| {
+            crates
+                .iter()
+                .rev()
+                .filter_map(|row| row.get(column).copied())
+                .filter(|&c| c != ' ')
+                .collect()
+        })
+        .collect()
+}
+
+/// Follows every move on a copy of the stacks, a crate at a time or, with
+/// `in_blocks`, a whole pile at once, and reads off the top crates.
+fn top_crates(moves: &[(usize, usize, usize)], in_blocks: bool) -> Result<String> {
+    let mut stacks = stacks_bottom_up(STACKS);
+    for &(count, from, to) in moves {
+        let height = stacks[from].len();
+        if count > height {
+            return Err(AocError::parse(format!(
+                "cannot move {} crates off a stack of {}",
+                count, height
+            )));
+        }
+        if in_blocks {
+            let pile = stacks[from].split_off(height - count);
+            stacks[to].extend(pile);
+        } else {
+            for _ in 0..count {
+                let top = stacks[from].pop().expect("the stack was tall enough");
+                stacks[to].push(top);
+            }
+        }
+    }
+    stacks
+        .iter()
+        .map(|stack| stack.last().copied())
+        .collect::<Option<String>>()
+        .ok_or_else(|| AocError::parse("a stack ends up empty"))
+}
+
+pub struct Day05 {
+    lines: Vec<String>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &str) -> Result<Day05> {
+        Ok(Day05 {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut stacks = create_stacks(STACKS);
+        let moves = self.lines.iter().map(|x| parse_line(x.to_string()));
+        for m in moves {
+            for _ in 0..m.0 {
+                move_from_i_to_j(&mut stacks, m.1, m.2)
+            }
+        }
+        Ok(message(&stacks).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut stacks = create_stacks(STACKS);
+        debug!("stacks before moving: {:?}", stacks);
+        let moves = self.lines.iter().map(|x| parse_line(x.to_string()));
+        for m in moves {
+            move_block_from_i_to_j(&mut stacks, m.0, m.1, m.2)
+        }
+        debug!("stacks after moving: {:?}", stacks);
+        Ok(message(&stacks).into())
+    }
+
+    /// Moves between the stacks above that never empty one.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let mut heights: Vec<usize> = stacks_bottom_up(STACKS).iter().map(Vec::len).collect();
+        let mut moves = vec![];
+        for _ in 0..rng.range(1..=40) {
+            let from = rng.index(heights.len());
+            if heights[from] < 2 {
+                continue;
+            }
+            let to = rng.index(heights.len());
+            let count = rng.range(1..=heights[from] as i64 - 1) as usize;
+            heights[from] -= count;
+            heights[to] += count;
+            moves.push(format!("move {} from {} to {}", count, from + 1, to + 1));
+        }
+        Ok(moves.join("\n"))
+    }
+
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let stack_count = stacks_bottom_up(STACKS).len();
+        let mut moves = vec![];
+        for mut line in parse::lines(input) {
+            line.literal("move ")?;
+            let count = line.number()?;
+            let stack = |line: &mut parse::Scanner| match line.number::<usize>() {
+                Ok(n) if (1..=stack_count).contains(&n) => Ok(n - 1),
+                _ => Err(line.error(format!("a stack from 1 to {}", stack_count))),
+            };
+            line.literal(" from ")?;
+            let from = stack(&mut line)?;
+            line.literal(" to ")?;
+            let to = stack(&mut line)?;
+            line.end()?;
+            moves.push((count, from, to));
+        }
+        Ok((
+            top_crates(&moves, false)?.into(),
+            Some(top_crates(&moves, true)?.into()),
+        ))
+    }
+}
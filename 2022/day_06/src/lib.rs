@@ -0,0 +1,76 @@
+use aoc_common::differential::Rng;
+use aoc_common::{Answer, AocError, Result, Solution};
+use std::collections::HashSet;
+
+/// Number of characters read before the first `size` distinct characters in a row.
+fn parse_line(line: String, size: usize) -> Result<usize> {
+    line.chars().collect::<Vec<char>>()[..]
+        .windows(size)
+        .position(|x: &[char]| HashSet::<&char>::from_iter(x).len() >= size)
+        .map(|r| r + size)
+        .ok_or_else(|| AocError::no_solution(format!("no {} distinct characters in a row", size)))
+}
+
+/// The same as [`parse_line`], comparing every pair of characters in every window.
+fn parse_line_slowly(line: &str, size: usize) -> Result<usize> {
+    let chars: Vec<char> = line.chars().collect();
+    for end in size..=chars.len() {
+        let window = &chars[end - size..end];
+        let distinct = (0..size).all(|i| (i + 1..size).all(|j| window[i] != window[j]));
+        if distinct {
+            return Ok(end);
+        }
+    }
+    Err(AocError::no_solution(format!(
+        "no {} distinct characters in a row",
+        size
+    )))
+}
+
+pub struct Day06 {
+    line: String,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &str) -> Result<Day06> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::parse("empty input"))?;
+        Ok(Day06 {
+            line: line.to_string(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(parse_line(self.line.clone(), 4)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(parse_line(self.line.clone(), 14)?.into())
+    }
+
+    /// A stream of letters with a start-of-message marker somewhere in it.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let mut alphabet: Vec<char> = ('a'..='z').collect();
+        rng.shuffle(&mut alphabet);
+        let letters = &alphabet[..rng.range(2..=26) as usize];
+        let mut stream: Vec<char> = (0..rng.range(1..=200))
+            .map(|_| *rng.pick(letters))
+            .collect();
+        let at = rng.index(stream.len() + 1);
+        stream.splice(at..at, alphabet[..14].iter().copied());
+        Ok(stream.into_iter().collect())
+    }
+
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let line = input
+            .lines()
+            .next()
+            .ok_or_else(|| AocError::parse("empty input"))?;
+        Ok((
+            parse_line_slowly(line, 4)?.into(),
+            Some(parse_line_slowly(line, 14)?.into()),
+        ))
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::parse;
+use aoc_common::{debug, trace, Answer, AocError, Result, Solution};
+
+pub struct Day07 {
+    files: Vec<(Vec<String>, u32)>,
+    dirs: Vec<Vec<String>>,
+}
+
+impl Solution for Day07 {
+    fn parse(input: &str) -> Result<Day07> {
+        let mut files: Vec<(Vec<String>, u32)> = vec![];
+        let mut dirs: Vec<Vec<String>> = vec![vec!["/".into()]];
+        let mut path: Vec<String> = vec![];
+
+        for mut scanner in parse::lines(input) {
+            let line = scanner.rest();
+            if let Some(("$", p)) = line.split_once(" cd ") {
+                match p {
+                    ".." => {
+                        path.pop();
+                    }
+                    _ => path.push(p.to_string()),
+                }
+            } else if let Some(("$ ", _)) = line.split_once("ls") {
+            } else if let Some(("dir", d)) = line.split_once(" ") {
+                let dir_path = [path.clone(), vec![d.to_string()]].concat();
+                dirs.push(dir_path)
+            } else {
+                let size = scanner.number()?;
+                scanner.literal(" ")?;
+                let file_path = [path.clone(), vec![scanner.rest().to_string()]].concat();
+                files.push((file_path, size))
+            }
+        }
+        debug!("{} files in {} directories", files.len(), dirs.len());
+        Ok(Day07 { files, dirs })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut sum = 0;
+
+        for d in self.dirs.clone() {
+            let dir_size = self
+                .files
+                .iter()
+                .filter(|file| file.0.iter().zip(d.clone()).all(|(ff, dd)| *ff == dd))
+                .map(|file| file.1)
+                .sum::<u32>();
+            trace!("/{} holds {}", d.join("/"), dir_size);
+            if dir_size < 100000 {
+                sum += dir_size
+            }
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let files = &self.files;
+        let total_size = files.iter().map(|file| file.1).sum::<u32>();
+        debug!("total size {}", total_size);
+        let to_free = total_size.saturating_sub(40000000);
+
+        let a = self
+            .dirs
+            .iter()
+            .filter(|dir| {
+                files
+                    .iter()
+                    .filter(|file| file.0.iter().zip(dir.iter()).all(|(ff, dd)| ff == dd))
+                    .map(|file| file.1)
+                    .sum::<u32>()
+                    > to_free
+            })
+            .map(|dir| {
+                files
+                    .iter()
+                    .filter(|file| file.0.iter().zip(dir.iter()).all(|(ff, dd)| ff == dd))
+                    .map(|file| file.1)
+                    .sum::<u32>()
+            })
+            .min();
+
+        a.map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("no directory is big enough to free the space"))
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::grid::{Pos, DIRECTIONS4};
+use aoc_common::image::{self, Image};
+use aoc_common::{trace, Answer, AocError, Grid, Result, Solution};
+
+fn check_slices(element: u32, slices: Vec<Vec<u32>>) -> bool {
+    let result = slices
+        .iter()
+        .any(|slice| element > *slice.iter().max().unwrap_or(&0) || slice.is_empty());
+    result
+}
+
+fn scenic_score(element: u32, slices: Vec<Vec<u32>>) -> usize {
+    let p = slices
+        .iter()
+        .map(|slice| {
+            (
+                slice.iter().take_while(|&x| x < &element).count(),
+                slice.len(),
+            )
+        })
+        .map(|(s, l)| if s < l { s + 1 } else { s })
+        .product();
+    trace!("height {} sees {:?}, score {}", element, slices, p);
+    p
+}
+
+/// The heights seen looking from the tree at `pos` towards each edge, nearest first.
+fn slices(map: &Grid<u32>, pos: Pos) -> Vec<Vec<u32>> {
+    DIRECTIONS4
+        .iter()
+        .map(|&direction| map.ray(pos, direction).map(|(_, &h)| h).collect())
+        .collect()
+}
+
+pub struct Day08 {
+    map: Grid<u32>,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &str) -> Result<Day08> {
+        Ok(Day08 {
+            map: Grid::parse_with(input, |c| {
+                c.to_digit(10)
+                    .ok_or_else(|| AocError::parse(format!("{:?} is not a height", c)))
+            })?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let total = self
+            .map
+            .iter()
+            .filter(|&(pos, &element)| check_slices(element, slices(&self.map, pos)))
+            .count();
+        Ok(total.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let max_scenic_score = self
+            .map
+            .iter()
+            .map(|(pos, &element)| scenic_score(element, slices(&self.map, pos)))
+            .max()
+            .unwrap_or(0);
+        Ok(max_scenic_score.into())
+    }
+
+    /// A heatmap of every tree's scenic score, with the best spot in red.
+    fn image(&self) -> Result<Image> {
+        let scores = Grid::from_rows(
+            (0..self.map.height())
+                .map(|y| {
+                    (0..self.map.width())
+                        .map(|x| scenic_score(self.map[(x, y)], slices(&self.map, (x, y))))
+                        .collect()
+                })
+                .collect(),
+        )?;
+        let best = scores.iter().map(|(_, &s)| s).max().unwrap_or(0);
+        let mut image = Image::from_grid(&scores, |&s| image::gradient(s as f64, best as f64));
+        if let Some((x, y)) = scores.find(&best) {
+            image.set(x, y, image::RED);
+        }
+        Ok(image.scale(4))
+    }
+}
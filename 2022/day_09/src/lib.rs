@@ -0,0 +1,197 @@
+use aoc_common::animate::{Animation, Colour, Frame};
+use aoc_common::differential::Rng;
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{debug, trace, Answer, AocError, Result, Solution};
+use std::collections::HashSet;
+
+type Knot = Point2<i32>;
+
+/// Moves the head, the last knot of `snake_positions`, one step and drags the rest
+/// behind it.
+fn step_snake(snake_positions: &mut [Knot], direction: Direction) {
+    let head = snake_positions.len() - 1;
+    snake_positions[head] += direction.delta();
+    for i in (0..head).rev() {
+        if snake_positions[i].chebyshev(snake_positions[i + 1]) > 1 {
+            snake_positions[i] = snake_positions[i].step_towards(snake_positions[i + 1]);
+        };
+    }
+    trace!("knots at {:?}", snake_positions);
+}
+
+fn move_whole_snake(
+    snake_positions: &mut [Knot],
+    direction: Direction,
+    num_steps_int: u32,
+    tail_positions: &mut Vec<Knot>,
+) {
+    (0..num_steps_int).for_each(|_| {
+        step_snake(snake_positions, direction);
+        tail_positions.push(snake_positions[0]);
+    })
+}
+
+type Move = (Direction, u32);
+
+fn parse_move(mut line: Scanner) -> Result<Move> {
+    let direction = line.one_of(&["U", "D", "L", "R"])?;
+    let direction = direction
+        .chars()
+        .next()
+        .and_then(Direction::from_char)
+        .ok_or_else(|| line.error("a direction"))?;
+    line.literal(" ")?;
+    let num_steps = line.number()?;
+    line.end()?;
+    Ok((direction, num_steps))
+}
+
+fn parse_line(&(direction, num_steps_int): &Move, snake_positions: &mut [Knot]) -> Vec<Knot> {
+    let mut tail_positions: Vec<Knot> = vec![];
+    move_whole_snake(
+        snake_positions,
+        direction,
+        num_steps_int,
+        &mut tail_positions,
+    );
+    debug!(
+        "after {:?} {}: {:?}",
+        direction, num_steps_int, snake_positions
+    );
+    tail_positions
+}
+
+/// Draws the rope, head `H` first, over the squares its tail has visited.
+fn draw(snake_positions: &[Knot], visited: &HashSet<Knot>) -> Frame {
+    let mut frame = Frame::new();
+    frame.set(0, 0, 's', Colour::Grey);
+    for p in visited {
+        frame.set(p.x as i64, p.y as i64, '#', Colour::Grey);
+    }
+    let head = snake_positions.len() - 1;
+    for (i, p) in snake_positions.iter().enumerate() {
+        let (c, colour) = match head - i {
+            0 => ('H', Colour::Red),
+            n => (
+                char::from_digit(n as u32, 36).unwrap_or('T'),
+                Colour::Yellow,
+            ),
+        };
+        frame.set(p.x as i64, p.y as i64, c, colour);
+    }
+    let head = snake_positions[head];
+    frame.focus(head.x as i64, head.y as i64);
+    frame
+}
+
+/// How many squares the last of `knots` knots visits, moving each knot with plain
+/// arithmetic on pairs.
+fn visited_slowly(moves: &[((i32, i32), u32)], knots: usize) -> usize {
+    let mut rope = vec![(0, 0); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for &((dx, dy), steps) in moves {
+        for _ in 0..steps {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for i in 1..knots {
+                let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                if x.abs() > 1 || y.abs() > 1 {
+                    rope[i] = (rope[i].0 + x.signum(), rope[i].1 + y.signum());
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+    visited.len()
+}
+
+pub struct Day09 {
+    moves: Vec<Move>,
+}
+
+impl Day09 {
+    fn visited_by_tail(&self, knots: usize) -> usize {
+        let mut snake_positions = vec![Knot::origin(); knots];
+        let r = self
+            .moves
+            .iter()
+            .flat_map(|m| parse_line(m, &mut snake_positions))
+            .collect::<HashSet<_>>();
+        r.len()
+    }
+}
+
+impl Solution for Day09 {
+    fn parse(input: &str) -> Result<Day09> {
+        Ok(Day09 {
+            moves: parse::lines(input)
+                .map(parse_move)
+                .collect::<Result<Vec<Move>>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.visited_by_tail(2).into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.visited_by_tail(10).into())
+    }
+
+    /// A rope wandering about for up to a hundred moves.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let moves: Vec<String> = (0..rng.range(1..=100))
+            .map(|_| format!("{} {}", rng.pick(&['U', 'D', 'L', 'R']), rng.range(1..=12)))
+            .collect();
+        Ok(moves.join("\n"))
+    }
+
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let mut moves = vec![];
+        for mut line in parse::lines(input) {
+            let delta = match line.one_of(&["U ", "D ", "L ", "R "])? {
+                "U " => (0, 1),
+                "D " => (0, -1),
+                "L " => (-1, 0),
+                _ => (1, 0),
+            };
+            match line.number()? {
+                0 => return Err(line.error("a move of at least one step")),
+                steps => moves.push((delta, steps)),
+            }
+            line.end()?;
+        }
+        if moves.is_empty() {
+            return Err(AocError::parse("no moves"));
+        }
+        Ok((
+            visited_slowly(&moves, 2).into(),
+            Some(visited_slowly(&moves, 10).into()),
+        ))
+    }
+
+    /// The ten knot rope of part 2, one step at a time.
+    fn animate(&self, animation: &mut Animation) -> Result<()> {
+        let mut snake_positions = vec![Knot::origin(); 10];
+        let mut visited = HashSet::from([Knot::origin()]);
+        for (n, &(direction, num_steps)) in self.moves.iter().enumerate() {
+            for _ in 0..num_steps {
+                step_snake(&mut snake_positions, direction);
+                visited.insert(snake_positions[0]);
+                let mut frame = draw(&snake_positions, &visited);
+                frame.caption(format!(
+                    "move {}/{}: {:?} {}, tail visited {}",
+                    n + 1,
+                    self.moves.len(),
+                    direction,
+                    num_steps,
+                    visited.len()
+                ));
+                if !animation.show(&frame) {
+                    return Ok(());
+                }
+            }
+        }
+        Ok(())
+    }
+}
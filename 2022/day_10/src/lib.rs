@@ -0,0 +1,90 @@
+use aoc_common::animate::{Animation, Colour, Frame};
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{Answer, AocError, Result, Solution};
+
+fn parse_line(mut line: Scanner, register_values: &mut Vec<(i32, i32)>) -> Result<()> {
+    let last_register_value = *register_values.last().unwrap_or(&(1, 1));
+    match line.one_of(&["noop", "addx "])? {
+        "noop" => register_values.push(last_register_value),
+        _ => {
+            let int_num = line.number::<i32>()?;
+            register_values.push(last_register_value);
+            register_values.push((last_register_value.1, last_register_value.1 + int_num))
+        }
+    }
+    line.end()
+}
+
+pub struct Day10 {
+    register_values: Vec<(i32, i32)>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &str) -> Result<Day10> {
+        let mut register_values: Vec<(i32, i32)> = vec![(1, 1)];
+        for line in parse::lines(input) {
+            parse_line(line, &mut register_values)?;
+        }
+        Ok(Day10 { register_values })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let register_values = &self.register_values;
+        let mut sum = 0;
+        for x in [20, 60, 100, 140, 180, 220] {
+            let (_, value) = register_values.get(x - 1).ok_or_else(|| {
+                AocError::no_solution(format!("the program stops before cycle {}", x))
+            })?;
+            sum += (x as i32) * value;
+        }
+        Ok(sum.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self
+            .register_values
+            .chunks(40)
+            .map(|chunk| {
+                chunk
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_before, after))| {
+                        if [after - 1, *after, after + 1].contains(&(i as i32)) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+            .into())
+    }
+
+    /// The CRT drawing part 2's screen one cycle at a time, with the sprite under it.
+    fn animate(&self, animation: &mut Animation) -> Result<()> {
+        let mut frame = Frame::new();
+        for (cycle, (_before, after)) in self.register_values.iter().enumerate().take(240) {
+            let (x, y) = ((cycle % 40) as i64, (cycle / 40) as i64);
+            let lit = (after - 1..=after + 1).contains(&(x as i32));
+            frame.set(
+                x,
+                y,
+                if lit { '#' } else { '.' },
+                if lit { Colour::Green } else { Colour::Grey },
+            );
+            frame.text(0, 7, &" ".repeat(40), Colour::Plain);
+            for sprite in (after - 1..=after + 1).filter(|x| (0..40).contains(x)) {
+                frame.set(sprite as i64, 7, '=', Colour::Cyan);
+            }
+            frame.set(x, 8, '^', Colour::Red);
+            frame.caption(format!("cycle {}, X = {}", cycle + 1, after));
+            if !animation.show(&frame) {
+                break;
+            }
+            frame.set(x, 8, ' ', Colour::Plain);
+        }
+        Ok(())
+    }
+}
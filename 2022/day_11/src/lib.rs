@@ -0,0 +1,291 @@
+use aoc_common::budget;
+use aoc_common::differential::Rng;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{debug, Answer, AocError, Result, Solution};
+use num::integer::lcm;
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Eq)]
+struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    test: u64,
+    if_true: usize,
+    if_false: usize,
+    count: u64,
+}
+
+impl Monkey {
+    /// Inspects the first item, calms the worry level with `relief` and says
+    /// which monkey it is thrown to.
+    fn play(&mut self, relief: &dyn Fn(u64) -> u64) -> (u64, usize) {
+        let item = self.items[0];
+        self.items = self.items[1..].to_vec();
+        self.count += 1;
+        let new_worry_level = relief(self.operation.apply(item));
+        if new_worry_level.is_multiple_of(self.test) {
+            (new_worry_level, self.if_true)
+        } else {
+            (new_worry_level, self.if_false)
+        }
+    }
+}
+
+impl Ord for Monkey {
+    fn cmp(&self, other: &Monkey) -> Ordering {
+        self.count.cmp(&other.count)
+    }
+}
+
+impl PartialOrd for Monkey {
+    fn partial_cmp(&self, other: &Monkey) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Monkey {
+    fn eq(&self, other: &Monkey) -> bool {
+        self.count == other.count
+    }
+}
+
+/// What a monkey does to the worry level of an item it inspects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    fn apply(self, old: u64) -> u64 {
+        match self {
+            Operation::Add(n) => old + n,
+            Operation::Multiply(n) => old * n,
+            Operation::Square => old * old,
+        }
+    }
+}
+
+/// Reads the right-hand side of an operation, like `old * 19` or `old * old`.
+fn parse_operation(pattern: &str) -> Result<Operation> {
+    let unknown = || AocError::parse(format!("unknown operation {:?}", pattern));
+    match pattern.split(' ').collect::<Vec<&str>>()[..] {
+        ["old", "*", "old"] => Ok(Operation::Square),
+        ["old", operator, n] => {
+            let n = n.parse().map_err(|_| unknown())?;
+            match operator {
+                "+" => Ok(Operation::Add(n)),
+                "*" => Ok(Operation::Multiply(n)),
+                _ => Err(unknown()),
+            }
+        }
+        _ => Err(unknown()),
+    }
+}
+
+/// One monkey's notes: items, operation, divisor and who it throws to when the
+/// test passes and when it fails.
+type Notes = (Vec<u64>, Operation, u64, [usize; 2]);
+
+fn parse_notes(number: usize, block: &str) -> Result<Notes> {
+    let mut lines = parse::lines(block);
+    let mut line = |prefix: &str| -> Result<Scanner<'_>> {
+        let mut line = lines
+            .next()
+            .ok_or_else(|| AocError::parse(format!("monkey {} stops early", number)))?;
+        line.literal(prefix)?;
+        Ok(line)
+    };
+    let mut header = line("Monkey ")?;
+    if header.number::<usize>()? != number {
+        return Err(header.error(format!("monkey {}", number)));
+    }
+    header.literal(":")?;
+    let mut items = line("  Starting items: ")?;
+    let items = items.list(", ", |item| item.number())?;
+    let operation = line("  Operation: new = ")?;
+    let operation =
+        parse_operation(operation.rest()).map_err(|_| operation.error("an operation"))?;
+    let mut test = line("  Test: divisible by ")?;
+    let test = match test.number()? {
+        0 => return Err(test.error("a divisor above 0")),
+        n => n,
+    };
+    let mut targets = [0; 2];
+    for (target, outcome) in targets.iter_mut().zip(["true", "false"]) {
+        let mut throw = line(&format!("    If {}: throw to monkey ", outcome))?;
+        *target = throw.number()?;
+        if *target == number {
+            return Err(throw.error("another monkey"));
+        }
+        throw.end()?;
+    }
+    Ok((items, operation, test, targets))
+}
+
+/// Reads every monkey's notes, checking that there are at least two monkeys and
+/// that each throws only to monkeys that exist.
+fn parse_monkeys(input: &str) -> Result<Vec<Notes>> {
+    let notes = input
+        .trim_end()
+        .split("\n\n")
+        .enumerate()
+        .map(|(number, block)| parse_notes(number, block))
+        .collect::<Result<Vec<Notes>>>()?;
+    if notes.len() < 2 {
+        return Err(AocError::parse("it takes two monkeys to play"));
+    }
+    if let Some((_, _, _, targets)) = notes
+        .iter()
+        .find(|(_, _, _, targets)| targets.iter().any(|&t| t >= notes.len()))
+    {
+        return Err(AocError::parse(format!(
+            "there is no monkey {}",
+            targets.iter().max().unwrap_or(&0)
+        )));
+    }
+    Ok(notes)
+}
+
+/// Plays `rounds` rounds with worry levels kept in `u128`, calming them with
+/// `relief`, and multiplies the two highest inspection counts. Fails if a worry
+/// level outgrows the `u64` the solution keeps them in.
+fn monkey_business_slowly(
+    notes: &[Notes],
+    rounds: usize,
+    relief: &dyn Fn(u128) -> u128,
+) -> Result<u64> {
+    let mut held: Vec<VecDeque<u128>> = notes
+        .iter()
+        .map(|(items, ..)| items.iter().map(|&item| item as u128).collect())
+        .collect();
+    let mut inspected = vec![0_u64; notes.len()];
+    for _ in 0..rounds {
+        for (monkey, &(_, operation, test, targets)) in notes.iter().enumerate() {
+            while let Some(old) = held[monkey].pop_front() {
+                let new = match operation {
+                    Operation::Add(n) => old.checked_add(n as u128),
+                    Operation::Multiply(n) => old.checked_mul(n as u128),
+                    Operation::Square => old.checked_mul(old),
+                }
+                .filter(|&new| new <= u64::MAX as u128)
+                .ok_or_else(|| AocError::parse("worry levels grow too big"))?;
+                let new = relief(new);
+                let target = targets[usize::from(!new.is_multiple_of(test as u128))];
+                held[target].push_back(new);
+                inspected[monkey] += 1;
+            }
+        }
+    }
+    inspected.sort_unstable();
+    Ok(inspected.iter().rev().take(2).product())
+}
+
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Day11 {
+    fn monkey_business(&self, rounds: u32, relief: &dyn Fn(u64) -> u64) -> Result<u64> {
+        let mut monkeys = self.monkeys.clone();
+        for round in 0..rounds {
+            budget::check()?;
+            if round % 1000 == 0 {
+                budget::progress(format!("round {} of {}", round, rounds));
+            }
+            for i in 0..monkeys.len() {
+                while !monkeys[i].items.is_empty() {
+                    let (item, give_to) = monkeys[i].play(relief);
+                    monkeys[give_to].items.push(item);
+                }
+            }
+        }
+        monkeys.sort();
+        Ok(monkeys
+            .iter()
+            .rev()
+            .take(2)
+            .map(|monkey| monkey.count)
+            .product())
+    }
+}
+
+impl Solution for Day11 {
+    fn parse(input: &str) -> Result<Day11> {
+        let monkeys = parse_monkeys(input)?
+            .into_iter()
+            .map(|(items, operation, test, [if_true, if_false])| Monkey {
+                items,
+                operation,
+                test,
+                if_true,
+                if_false,
+                count: 0,
+            })
+            .collect();
+        Ok(Day11 { monkeys })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Ok(self.monkey_business(20, &|worry| worry / 3)?.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let lcm = self.monkeys.iter().map(|x| x.test).reduce(lcm).unwrap_or(1);
+        debug!("keeping worry levels modulo {}", lcm);
+        Ok(self.monkey_business(10000, &|worry| worry % lcm)?.into())
+    }
+
+    /// Two to eight monkeys with prime divisors, throwing a few items about.
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let mut primes = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut primes);
+        let count = rng.range(2..=8) as usize;
+        // Worry levels only stay small enough for the reference to check part 2
+        // when every monkey adds.
+        let calm = rng.chance(0.5);
+        // Squaring makes worry levels explode within 20 rounds unless the items
+        // soon move on, so only some inputs have a monkey that does it.
+        let squarer = (!calm && rng.chance(0.5)).then(|| rng.index(count));
+        let monkeys: Vec<String> = (0..count)
+            .map(|monkey| {
+                let items: Vec<String> = (0..rng.range(1..=4))
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect();
+                let operation = if Some(monkey) == squarer {
+                    "old * old".to_string()
+                } else if calm || rng.chance(0.5) {
+                    format!("old + {}", rng.range(1..=8))
+                } else {
+                    format!("old * {}", rng.range(2..=19))
+                };
+                let mut other = || (monkey + 1 + rng.index(count - 1)) % count;
+                format!(
+                    "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}",
+                    monkey,
+                    items.join(", "),
+                    operation,
+                    primes[monkey],
+                    other(),
+                    other()
+                )
+            })
+            .collect();
+        Ok(monkeys.join("\n\n"))
+    }
+
+    /// Plays every round with the worry levels as they are, in `u128`, with none of
+    /// the solution's modular arithmetic. Part 2 lets them grow for 10000 rounds, so
+    /// it is only worked out for inputs where the monkeys just add.
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let notes = parse_monkeys(input)?;
+        Ok((
+            monkey_business_slowly(&notes, 20, &|worry| worry / 3)?.into(),
+            monkey_business_slowly(&notes, 10000, &|worry| worry)
+                .ok()
+                .map(Answer::from),
+        ))
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::grid::Pos;
+use aoc_common::image::{self, Image};
+use aoc_common::parallel::Pool;
+use aoc_common::search::bfs;
+use aoc_common::{Answer, AocError, Grid, Result, Solution};
+
+fn successors(board: &Grid<char>, pos: Pos) -> Vec<Pos> {
+    let height = lookup_height(board[pos]);
+    board
+        .neighbours4(pos)
+        .filter(|&p| lookup_height(board[p]) <= height + 1)
+        .collect()
+}
+
+static ALPHA: &str = "abcdefghijklmnopqrstuvwxyz";
+
+fn lookup_height(c: char) -> u32 {
+    match c {
+        'E' => 25,
+        'S' => 0,
+        _ => ALPHA.chars().position(|x| x == c).unwrap() as u32,
+    }
+}
+
+fn lookup_goal(board: &Grid<char>, c: char) -> Result<Pos> {
+    board
+        .find(&c)
+        .ok_or_else(|| AocError::parse(format!("no {} on the map", c)))
+}
+
+fn lookup_low_points(board: &Grid<char>) -> Vec<Pos> {
+    board
+        .iter()
+        .filter(|&(_, &square)| square == 'a' || square == 'S')
+        .map(|(pos, _)| pos)
+        .collect()
+}
+
+pub struct Day12 {
+    map: Grid<char>,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &str) -> Result<Day12> {
+        let map = Grid::parse(input)?;
+        if let Some((_, c)) = map
+            .iter()
+            .find(|&(_, &c)| !(c.is_ascii_lowercase() || c == 'S' || c == 'E'))
+        {
+            return Err(AocError::parse(format!("{:?} is not a height", c)));
+        }
+        Ok(Day12 { map })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let map = &self.map;
+        let start = lookup_goal(map, 'S')?;
+        let end = lookup_goal(map, 'E')?;
+        let result = bfs(start, &|&p: &Pos| successors(map, p), |p| *p == end);
+        result
+            .map(|path| (path.len() - 1).into())
+            .ok_or_else(|| AocError::no_solution("no path from S to E"))
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let map = self.map.clone();
+        let end = lookup_goal(&map, 'E')?;
+        Pool::current()
+            .map(lookup_low_points(&map), move |&low_point| {
+                bfs(low_point, &|&p: &Pos| successors(&map, p), |p| *p == end)
+            })
+            .into_iter()
+            .flatten()
+            .map(|path| path.len() - 1)
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| AocError::no_solution("no path from any low point to E"))
+    }
+
+    /// The heightmap, darker for lower ground, with part 1's path from S to E in red.
+    fn image(&self) -> Result<Image> {
+        let map = &self.map;
+        let start = lookup_goal(map, 'S')?;
+        let end = lookup_goal(map, 'E')?;
+        let mut image = Image::from_grid(map, |&c| image::gradient(lookup_height(c) as f64, 25.0));
+        for (x, y) in bfs(start, &|&p: &Pos| successors(map, p), |p| *p == end).unwrap_or_default()
+        {
+            image.set(x, y, image::RED);
+        }
+        Ok(image.scale(4))
+    }
+}
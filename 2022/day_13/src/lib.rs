@@ -1,11 +1,8 @@
+use aoc_common::{Answer, Solution};
 use serde_json::Value::Array;
 use serde_json::Value::Number;
 use serde_json::{Result, Value};
 use std::cmp::Ordering;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
 
 fn parse_input(data: &str) -> Result<Value> {
     // Parse the string of data into serde_json::Value.
@@ -13,16 +10,6 @@ fn parse_input(data: &str) -> Result<Value> {
     Ok(v)
 }
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
 #[derive(PartialEq, Debug)]
 enum Status {
     Correct,
@@ -52,9 +39,9 @@ fn compare(value_0: &Value, value_1: &Value) -> Status {
         (Array(_), Number(_)) => compare(value_0, &Array(vec![value_1.clone()])),
         (Number(_), Array(_)) => compare(&Array(vec![value_0.clone()]), value_1),
         (Array(x_0), Array(x_1)) => {
-            if x_0.len() > 0 && x_1.len() == 0 {
+            if !x_0.is_empty() && x_1.is_empty() {
                 Status::Incorrect
-            } else if x_0.len() == 0 && x_1.len() > 0 {
+            } else if x_0.is_empty() && !x_1.is_empty() {
                 Status::Correct
             } else {
                 for i in 0..x_0.len().min(x_1.len()) {
@@ -80,36 +67,39 @@ fn decoder_packets() -> Vec<Value> {
     vec![parse_input("[[2]]").unwrap(), parse_input("[[6]]").unwrap()]
 }
 
-fn main() {
-    if let Some(arg1) = env::args().nth(1) {
-        // File hosts must exist in current path before this produces output
-        if let Ok(lines) = read_lines(arg1) {
-            // Consumes the iterator, returns an (Optional) String
-            // let r = lines
-            //     .filter_map(|x| x.ok())
-            //     .collect::<Vec<String>>()
-            //     .into_iter()
-            //     .map(|line| parse_input(&line))
-            //     .filter_map(|x| x.ok())
-            //     .collect::<Vec<Value>>()
-            //     .chunks(2)
-            //     .enumerate()
-            //     .filter(|(_, chunk)| compare(&chunk[0], &chunk[1]) == Status::Correct)
-            //     .map(|(i, _)| i + 1)
-            //     .sum::<usize>();
-            let mut p = lines
-                .filter_map(|x| x.ok())
-                .collect::<Vec<String>>()
-                .into_iter()
-                .map(|line| parse_input(&line))
+pub struct Day13 {
+    packets: Vec<Value>,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &str) -> aoc_common::Result<Day13> {
+        Ok(Day13 {
+            packets: input
+                .lines()
+                .map(parse_input)
                 .filter_map(|x| x.ok())
-                .collect::<Vec<Value>>();
+                .collect::<Vec<Value>>(),
+        })
+    }
 
-            p.append(&mut decoder_packets());
-            p.sort_by(|v_0, v_1| status_to_ordering(compare(v_0, v_1)));
-            let a = p.iter().position(|signal| signal.to_string() == "[[2]]").unwrap();
-            let b = p.iter().position(|signal| signal.to_string() == "[[6]]").unwrap();
-            println!("{:?}", (a + 1) * (b + 1))
-        }
+    fn part1(&self) -> aoc_common::Result<Answer> {
+        let r = self
+            .packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, chunk)| compare(&chunk[0], &chunk[1]) == Status::Correct)
+            .map(|(i, _)| i + 1)
+            .sum::<usize>();
+        Ok(r.into())
+    }
+
+    fn part2(&self) -> aoc_common::Result<Answer> {
+        let dividers = decoder_packets();
+        let mut p = self.packets.clone();
+        p.extend(dividers.iter().cloned());
+        p.sort_by(|v_0, v_1| status_to_ordering(compare(v_0, v_1)));
+        let a = p.iter().position(|signal| *signal == dividers[0]).unwrap();
+        let b = p.iter().position(|signal| *signal == dividers[1]).unwrap();
+        Ok(((a + 1) * (b + 1)).into())
     }
 }
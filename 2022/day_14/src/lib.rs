@@ -0,0 +1,195 @@
+use aoc_common::animate::{Animation, Colour, Frame};
+use aoc_common::budget;
+use aoc_common::geometry::Point2;
+use aoc_common::grid::Pos;
+use aoc_common::image::{self, Image};
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{Answer, AocError, Grid, Result, Solution};
+
+type Point = Point2<i32>;
+
+const STARTING_POINT: Point = Point::new(500, 0);
+
+/// Where a grain of sand tries to fall, in order: down, down-left and down-right.
+const FALLS: [Point; 3] = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)];
+
+fn parse_line(mut line: Scanner) -> Result<Vec<Point>> {
+    // Coordinates are never negative, and small enough for the cave to fit in memory.
+    let ps = line.list(" -> ", |p| {
+        let x = p.number::<u16>()?;
+        p.literal(",")?;
+        let y = p.number::<u16>()?;
+        Ok(Point::new(x.into(), y.into()))
+    })?;
+    line.end()?;
+    Ok(ps
+        .windows(2)
+        .flat_map(|w| w[0].line_to(w[1]))
+        .collect::<Vec<Point>>())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+/// The slice of cave sand can reach: down to the floor two below the lowest rock,
+/// and as far either side of the source as it is deep.
+#[derive(Debug, Clone)]
+struct Cave {
+    tiles: Grid<Tile>,
+    /// The x coordinate of the grid's first column.
+    left: i32,
+}
+
+impl Cave {
+    fn new(rocks: &[Point], max_depth: i32) -> Cave {
+        let floor = max_depth + 2;
+        let left = rocks
+            .iter()
+            .map(|p| p.x)
+            .chain([STARTING_POINT.x - floor - 1])
+            .min()
+            .unwrap();
+        let right = rocks
+            .iter()
+            .map(|p| p.x)
+            .chain([STARTING_POINT.x + floor + 1])
+            .max()
+            .unwrap();
+        let tiles = Grid::new((right - left + 1) as usize, (floor + 1) as usize, Tile::Air);
+        let mut cave = Cave { tiles, left };
+        let floor = Point::new(left, floor).line_to(Point::new(right, floor));
+        for p in rocks.iter().copied().chain(floor) {
+            cave.set(&p, Tile::Rock);
+        }
+        cave
+    }
+
+    fn pos(&self, p: &Point) -> Option<Pos> {
+        Some((
+            usize::try_from(p.x - self.left).ok()?,
+            usize::try_from(p.y).ok()?,
+        ))
+    }
+
+    fn is_free(&self, p: &Point) -> bool {
+        self.pos(p)
+            .and_then(|pos| self.tiles.get(pos))
+            .is_some_and(|&tile| tile == Tile::Air)
+    }
+
+    fn set(&mut self, p: &Point, tile: Tile) {
+        if let Some(cell) = self.pos(p).and_then(|pos| self.tiles.get_mut(pos)) {
+            *cell = tile;
+        }
+    }
+}
+
+/// Drops one grain of sand and returns where it stopped and whether it came to rest.
+/// `None` means the source is blocked and no more sand can settle.
+fn add_sand(cave: &mut Cave) -> Option<(Point, bool)> {
+    let mut p = STARTING_POINT;
+    while let Some(next) = FALLS.iter().map(|&fall| p + fall).find(|p| cave.is_free(p)) {
+        p = next;
+    }
+    if p.y == 0 {
+        return None;
+    }
+    let settled = cave.is_free(&p);
+    cave.set(&p, Tile::Sand);
+    Some((p, settled))
+}
+
+/// The rocks of `cave` and the sand source.
+fn draw(cave: &Cave) -> Frame {
+    let mut frame = Frame::new();
+    for ((x, y), &tile) in cave.tiles.iter() {
+        let x = x as i64 + cave.left as i64;
+        match tile {
+            Tile::Air => {}
+            Tile::Rock => frame.set(x, y as i64, '#', Colour::White),
+            Tile::Sand => frame.set(x, y as i64, 'o', Colour::Yellow),
+        }
+    }
+    frame.set(STARTING_POINT.x as i64, 0, '+', Colour::Cyan);
+    frame
+}
+
+pub struct Day14 {
+    cave: Cave,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &str) -> Result<Day14> {
+        let rocks: Vec<Point> = parse::lines(input)
+            .map(parse_line)
+            .collect::<Result<Vec<Vec<Point>>>>()?
+            .concat();
+        let max_depth = rocks
+            .iter()
+            .map(|p| p.y)
+            .max()
+            .ok_or_else(|| AocError::parse("no rocks in the scan"))?;
+        Ok(Day14 {
+            cave: Cave::new(&rocks, max_depth),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let mut cave = self.cave.clone();
+        let mut c = 0;
+        loop {
+            budget::check()?;
+            if c % 1000 == 0 {
+                budget::progress(format!("grain {}", c));
+            }
+            match add_sand(&mut cave) {
+                None => break,
+                Some(_) => c += 1,
+            }
+        }
+        Ok((c + 1).into())
+    }
+
+    /// The cave once part 2's sand has blocked the source.
+    fn image(&self) -> Result<Image> {
+        let mut cave = self.cave.clone();
+        while add_sand(&mut cave).is_some() {}
+        cave.set(&STARTING_POINT, Tile::Sand);
+        let image = Image::from_grid(&cave.tiles, |tile| match tile {
+            Tile::Air => image::BLACK,
+            Tile::Rock => image::GREY,
+            Tile::Sand => image::YELLOW,
+        });
+        Ok(image.scale(3))
+    }
+
+    /// Sand piling up grain by grain on the floor of part 2.
+    fn animate(&self, animation: &mut Animation) -> Result<()> {
+        let mut cave = self.cave.clone();
+        let mut frame = draw(&cave);
+        let mut last: Option<Point> = None;
+        let mut grains = 0;
+        while let Some((p, _)) = add_sand(&mut cave) {
+            grains += 1;
+            if let Some(last) = last {
+                frame.set(last.x as i64, last.y as i64, 'o', Colour::Yellow);
+            }
+            frame.set(p.x as i64, p.y as i64, 'o', Colour::Red);
+            frame.focus(p.x as i64, p.y as i64);
+            frame.caption(format!("grain {}", grains));
+            if !animation.show(&frame) {
+                break;
+            }
+            last = Some(p);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_common::budget;
+use aoc_common::geometry::Point2;
+use aoc_common::image::{self, Image};
+use aoc_common::parallel::Pool;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{Answer, AocError, IntervalSet, Result, Solution};
+use std::ops::RangeInclusive;
+
+type Point = Point2<i64>;
+
+/// A sensor and its closest beacon.
+fn parse_line(mut line: Scanner) -> Result<(Point, Point)> {
+    line.literal("Sensor at x=")?;
+    let sensor_x = line.number()?;
+    line.literal(", y=")?;
+    let sensor_y = line.number()?;
+    line.literal(": closest beacon is at x=")?;
+    let beacon_x = line.number()?;
+    line.literal(", y=")?;
+    let beacon_y = line.number()?;
+    line.end()?;
+    Ok((
+        Point::new(sensor_x, sensor_y),
+        Point::new(beacon_x, beacon_y),
+    ))
+}
+
+/// The stretch of `line` within `radius` of `center`, if the line gets that close.
+fn ball_line_intersects(center: &Point, radius: u64, line: i64) -> Option<RangeInclusive<i64>> {
+    let h = radius as i64 - (line - center.y).abs();
+    (h >= 0).then(|| center.x - h..=center.x + h)
+}
+
+static M: i64 = 4000000;
+static TUNING_MULTIPLIER: i64 = 4000000;
+
+fn covered_intervals(known_locations: &[(Point, Point)], line: i64) -> IntervalSet<i64> {
+    known_locations
+        .iter()
+        .filter_map(|loc| ball_line_intersects(&loc.0, loc.0.manhattan(loc.1) as u64, line))
+        .collect()
+}
+
+pub struct Day15 {
+    sensors: Vec<(Point, Point)>,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &str) -> Result<Day15> {
+        Ok(Day15 {
+            sensors: parse::lines(input)
+                .map(parse_line)
+                .collect::<Result<Vec<(Point, Point)>>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        // The lines are independent, so they are scanned a thousand at a time by
+        // each thread, keeping the first gap found. Going over the budget counts as
+        // a find, so the scan stops there.
+        let sensors = self.sensors.clone();
+        Pool::current()
+            .find_first(0..=M, 1000, move |line| {
+                if let Err(e) = budget::check() {
+                    return Some(Err(e));
+                }
+                if line % 100_000 == 0 {
+                    budget::progress(format!("line {} of {}", line, M));
+                }
+                let uncovered = covered_intervals(&sensors, line).complement(0..=M);
+                (uncovered.len() == 1).then(|| {
+                    let x = *uncovered.bounds().unwrap().start();
+                    Ok((x * TUNING_MULTIPLIER + line).into())
+                })
+            })
+            .unwrap_or_else(|| {
+                Err(AocError::no_solution(
+                    "no gap found for the distress beacon",
+                ))
+            })
+    }
+
+    /// The search area with each sensor's diamond of coverage, shrunk to at most
+    /// 800 pixels a side. Sensors are red, beacons blue.
+    fn image(&self) -> Result<Image> {
+        const MAX_SIDE: i64 = 800;
+        let side = (M + 1).min(MAX_SIDE);
+        let to_world = |pixel: i64| pixel * (M + 1) / side;
+        let radii: Vec<(Point, i64)> = self
+            .sensors
+            .iter()
+            .map(|&(sensor, beacon)| (sensor, sensor.manhattan(beacon)))
+            .collect();
+        let mut image = Image::new(side as usize, side as usize, image::BLACK);
+        for py in 0..side {
+            for px in 0..side {
+                let p = Point::new(to_world(px), to_world(py));
+                let covering = radii.iter().filter(|(s, r)| s.manhattan(p) <= *r).count();
+                if covering > 0 {
+                    let shade = image::gradient(covering as f64, radii.len().min(6) as f64);
+                    image.set(px as usize, py as usize, shade);
+                }
+            }
+        }
+        let to_pixel = |world: i64| world * side / (M + 1);
+        for &(sensor, beacon) in &self.sensors {
+            for (p, colour) in [(beacon, image::BLUE), (sensor, image::RED)] {
+                if (0..=M).contains(&p.x) && (0..=M).contains(&p.y) {
+                    image.set(to_pixel(p.x) as usize, to_pixel(p.y) as usize, colour);
+                }
+            }
+        }
+        let scale = (MAX_SIDE / side).max(1) as usize;
+        Ok(image.scale(scale))
+    }
+}
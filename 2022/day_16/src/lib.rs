@@ -0,0 +1,154 @@
+use aoc_common::budget;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{Answer, AocError, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+struct Valve {
+    label: String,
+    flow_rate: u32,
+    connections: Vec<String>,
+}
+
+impl Valve {
+    fn new(label: String, flow_rate: u32, connections: Vec<String>) -> Valve {
+        Valve {
+            label,
+            flow_rate,
+            connections,
+        }
+    }
+}
+
+fn parse_line(mut line: Scanner) -> Result<Valve> {
+    line.literal("Valve ")?;
+    let label = line.word()?.to_string();
+    line.literal(" has flow rate=")?;
+    let flow_rate = line.number()?;
+    line.one_of(&["; tunnels lead to valves ", "; tunnel leads to valve "])?;
+    let connections = line.list(", ", |s| s.word().map(str::to_string))?;
+    line.end()?;
+    Ok(Valve::new(label, flow_rate, connections))
+}
+
+/// Walks the tunnels a minute at a time, opening valves along the way. `seen` holds
+/// the most pressure any walk has had at each minute and valve, so walks that do
+/// no better are dropped.
+fn search(
+    time: u32,
+    current_valve: String,
+    pressure: u64,
+    max_so_far: &mut u64,
+    open_valves: &mut HashSet<String>,
+    seen: &mut HashMap<(u32, String), u64>,
+    valves: &HashMap<String, Valve>,
+) -> Result<()> {
+    budget::check()?;
+    let valve = |label: &String| {
+        valves
+            .get(label)
+            .ok_or_else(|| AocError::parse(format!("there is no valve {}", label)))
+    };
+    let cvc = current_valve.clone();
+
+    let is_seen = seen.get(&(time, cvc));
+
+    if is_seen.is_some() && is_seen.unwrap() >= &pressure {
+        return Ok(());
+    }
+
+    let cvc = current_valve.clone();
+
+    seen.insert((time, cvc), pressure);
+    if seen.len().is_multiple_of(10_000) {
+        budget::progress(format!("{} minutes and valves seen", seen.len()));
+    }
+
+    if time == 30 {
+        *max_so_far = (*max_so_far).max(pressure);
+    } else {
+        let net_pressure: u64 = open_valves
+            .iter()
+            .map(|v| valve(v).map(|v| v.flow_rate))
+            .sum::<Result<u32>>()? as u64;
+
+        let cvc = current_valve.clone();
+        let current = valve(&current_valve)?;
+        if !open_valves.contains(&current_valve) && current.flow_rate > 0 {
+            open_valves.insert(cvc);
+            let cvc = current_valve.clone();
+            search(
+                time + 1,
+                cvc,
+                pressure + net_pressure + current.flow_rate as u64,
+                max_so_far,
+                open_valves,
+                seen,
+                valves,
+            )?;
+            open_valves.remove(&current_valve);
+        }
+
+        for valve in current.connections.iter() {
+            search(
+                time + 1,
+                valve.clone(),
+                pressure + net_pressure,
+                max_so_far,
+                open_valves,
+                seen,
+                valves,
+            )?
+        }
+    }
+    Ok(())
+}
+
+pub struct Day16 {
+    valves: HashMap<String, Valve>,
+}
+
+impl Solution for Day16 {
+    fn parse(input: &str) -> Result<Day16> {
+        let mut valves = HashMap::new();
+        let mut connections = vec![];
+        for line in parse::lines(input) {
+            let number = line.line();
+            let valve = parse_line(line)?;
+            connections.extend(valve.connections.iter().map(|c| (number, c.clone())));
+            valves.insert(valve.label.clone(), valve);
+        }
+        if let Some((number, label)) = connections.iter().find(|(_, c)| !valves.contains_key(c)) {
+            return Err(AocError::parse(format!(
+                "line {}: there is no valve {}",
+                number, label
+            )));
+        }
+        if !valves.contains_key("AA") {
+            return Err(AocError::parse("there is no valve AA to start from"));
+        }
+        Ok(Day16 { valves })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let mut open_valves: HashSet<String> = HashSet::new();
+        let mut seen: HashMap<(u32, String), u64> = HashMap::new();
+        let mut max_so_far: u64 = 0;
+
+        search(
+            1,
+            "AA".to_string(),
+            0,
+            &mut max_so_far,
+            &mut open_valves,
+            &mut seen,
+            &self.valves,
+        )?;
+
+        Ok(max_so_far.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+}
@@ -1,75 +1,34 @@
-use regex::Regex;
+use aoc_common::budget;
+use aoc_common::parallel::Pool;
+use aoc_common::parse::{self, Scanner};
+use aoc_common::{debug, Answer, AocError, Result, Solution};
 use std::collections::HashSet;
-use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-#[allow(dead_code)]
-fn parse_line(line: String) -> BluePrint {
-    let ore_pattern: Regex = Regex::new(r"Each ore robot costs (\d+) ore.").unwrap();
-    let ore_captures = ore_pattern.captures(&line).unwrap();
-    let clay_pattern: Regex = Regex::new(r"Each clay robot costs (\d+) ore.").unwrap();
-    let clay_captures = clay_pattern.captures(&line).unwrap();
-    let obsidon_pattern: Regex =
-        Regex::new(r"Each obsidian robot costs (\d+) ore and (\d+) clay.").unwrap();
-    let obsidon_captures = obsidon_pattern.captures(&line).unwrap();
-    let geode_pattern: Regex =
-        Regex::new(r"Each geode robot costs (\d+) ore and (\d+) obsidian.").unwrap();
-    let geode_captures = geode_pattern.captures(&line).unwrap();
+fn parse_line(mut line: Scanner) -> Result<BluePrint> {
+    line.literal("Blueprint ")?;
+    line.number::<u32>()?;
+    line.literal(": Each ore robot costs ")?;
+    let ore_robot_cost = line.number()?;
+    line.literal(" ore. Each clay robot costs ")?;
+    let clay_robot_cost = line.number()?;
+    line.literal(" ore. Each obsidian robot costs ")?;
+    let obsidon_ore = line.number()?;
+    line.literal(" ore and ")?;
+    let obsidon_clay = line.number()?;
+    line.literal(" clay. Each geode robot costs ")?;
+    let geode_ore = line.number()?;
+    line.literal(" ore and ")?;
+    let geode_obsidian = line.number()?;
+    line.literal(" obsidian.")?;
+    line.end()?;
 
-    BluePrint {
-        ore_robot_cost: ore_captures
-            .get(1)
-            .unwrap()
-            .as_str()
-            .parse::<u32>()
-            .unwrap(),
-        clay_robot_cost: clay_captures
-            .get(1)
-            .unwrap()
-            .as_str()
-            .parse::<u32>()
-            .unwrap(),
-        obsidon_robot_cost: (
-            obsidon_captures
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse::<u32>()
-                .unwrap(),
-            obsidon_captures
-                .get(2)
-                .unwrap()
-                .as_str()
-                .parse::<u32>()
-                .unwrap(),
-        ),
-        geode_robot_cost: (
-            geode_captures
-                .get(1)
-                .unwrap()
-                .as_str()
-                .parse::<u32>()
-                .unwrap(),
-            geode_captures
-                .get(2)
-                .unwrap()
-                .as_str()
-                .parse::<u32>()
-                .unwrap(),
-        ),
-    }
+    Ok(BluePrint {
+        ore_robot_cost,
+        clay_robot_cost,
+        obsidon_robot_cost: (obsidon_ore, obsidon_clay),
+        geode_robot_cost: (geode_ore, geode_obsidian),
+    })
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -99,7 +58,7 @@ fn add(a0: [u32; 4], a1: [u32; 4], a2: Option<[i32; 4]>) -> [u32; 4] {
     output.map(|i| i.max(0) as u32)
 }
 
-fn children(state: &State, bp: &BluePrint, time_left: u32) -> Vec<State> {
+fn children(state: &State, bp: &BluePrint) -> Vec<State> {
     let mut children = vec![];
     if state.num_resources[0] >= bp.geode_robot_cost.0
         && state.num_resources[2] >= bp.geode_robot_cost.1
@@ -121,6 +80,7 @@ fn children(state: &State, bp: &BluePrint, time_left: u32) -> Vec<State> {
     };
     if state.num_resources[0] >= bp.obsidon_robot_cost.0
         && state.num_resources[1] >= bp.obsidon_robot_cost.1
+        && state.num_robots[2] < bp.geode_robot_cost.1
     {
         let new_state = State {
             num_resources: add(
@@ -142,7 +102,7 @@ fn children(state: &State, bp: &BluePrint, time_left: u32) -> Vec<State> {
     };
     if state.num_resources[0] >= bp.ore_robot_cost
         && state.num_robots[0]
-            <= bp.ore_robot_cost
+            < bp.ore_robot_cost
                 .max(bp.clay_robot_cost)
                 .max(bp.geode_robot_cost.0)
                 .max(bp.obsidon_robot_cost.0)
@@ -156,11 +116,10 @@ fn children(state: &State, bp: &BluePrint, time_left: u32) -> Vec<State> {
             ),
             num_robots: add(state.num_robots, [1, 0, 0, 0], None),
         };
-        if state.num_resources[1] + time_left > bp.obsidon_robot_cost.1 {
-            children.push(new_state);
-        }
+        children.push(new_state);
     }
-    if state.num_resources[0] >= bp.clay_robot_cost && state.num_robots[1] <= bp.geode_robot_cost.0 {
+    if state.num_resources[0] >= bp.clay_robot_cost && state.num_robots[1] < bp.obsidon_robot_cost.1
+    {
         // Buy clay robot...
         children.push(State {
             num_resources: add(
@@ -191,56 +150,89 @@ fn children(state: &State, bp: &BluePrint, time_left: u32) -> Vec<State> {
     children
 }
 
+/// Tries every choice a minute at a time up to minute 24, skipping states already
+/// reached by another route at the same minute.
 fn search(
     max_so_far: &mut u32,
     state: State,
     time: u32,
     bp: &BluePrint,
-    seen: &mut HashSet<State>,
-) {
-    if seen.contains(&state) {
-        return;
+    seen: &mut HashSet<(u32, State)>,
+) -> Result<()> {
+    budget::check()?;
+    // Even a new geode robot every remaining minute can't beat the best so far.
+    let time_left = 24 - time;
+    let most_possible = state.num_resources[3]
+        + state.num_robots[3] * time_left
+        + time_left * time_left.saturating_sub(1) / 2;
+    if most_possible <= *max_so_far {
+        return Ok(());
+    }
+    if !seen.insert((time, state)) {
+        return Ok(());
     }
-
-    seen.insert(state);
 
     if time == 24 {
         *max_so_far = (*max_so_far).max(state.num_resources[3]);
-        return;
     } else {
-        for child in children(&state, bp, 24 - time) {
-            search(max_so_far, child, time + 1, bp, seen)
+        for child in children(&state, bp) {
+            search(max_so_far, child, time + 1, bp, seen)?
         }
     }
+    Ok(())
 }
 
-fn main() {
-    if let Some(arg1) = env::args().nth(1) {
-        // File hosts must exist in current path before this produces output
-        if let Ok(lines) = read_lines(arg1) {
-            // Consumes the iterator, returns an (Optional) String
-            let r: Vec<_> = lines
-                .filter_map(|x| x.ok())
-                .collect::<Vec<String>>()
-                .into_iter()
-                .map(parse_line)
-                .collect();
+fn max_geodes(bp: &BluePrint) -> Result<u32> {
+    let starting_state = State {
+        num_resources: [0; 4],
+        num_robots: [1, 0, 0, 0],
+    };
+    let mut max_so_far: u32 = 0;
+    let mut seen = HashSet::new();
+    search(&mut max_so_far, starting_state, 0, bp, &mut seen)?;
+    Ok(max_so_far)
+}
 
-            let starting_state = State {
-                num_resources: [0; 4],
-                num_robots: [1, 0, 0, 0],
-            };
+/// The most geodes each blueprint opens, noting each one finished as progress.
+fn max_geodes_each(blueprints: &[BluePrint]) -> Result<Vec<u32>> {
+    let total = blueprints.len();
+    let done = AtomicUsize::new(0);
+    Pool::current()
+        .map(blueprints.to_vec(), move |bp| {
+            let geodes = max_geodes(bp)?;
+            let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+            budget::progress(format!("{} of {} blueprints", done, total));
+            Ok(geodes)
+        })
+        .into_iter()
+        .collect()
+}
 
-            let mut total: u64 = 0;
+pub struct Day19 {
+    blueprints: Vec<BluePrint>,
+}
 
-            for (i, bp) in r.iter().enumerate() {
-                let mut max_so_far: u32 = 0;
-                let mut seen = HashSet::new();
-                search(&mut max_so_far, starting_state, 0, &bp, &mut seen);
-                println!("{:?}", max_so_far);
-                total += (i as u64 + 1) * max_so_far as u64
-            }
-            println!("{:?}", total)
+impl Solution for Day19 {
+    fn parse(input: &str) -> Result<Day19> {
+        Ok(Day19 {
+            blueprints: parse::lines(input)
+                .filter(|line| !line.at_end())
+                .map(parse_line)
+                .collect::<Result<_>>()?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        let geodes = max_geodes_each(&self.blueprints)?;
+        let mut total: u64 = 0;
+        for (i, max_so_far) in geodes.into_iter().enumerate() {
+            debug!("blueprint {} opens {} geodes", i + 1, max_so_far);
+            total += (i as u64 + 1) * max_so_far as u64
         }
+        Ok(total.into())
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
     }
 }
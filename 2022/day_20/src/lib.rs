@@ -0,0 +1,75 @@
+use aoc_common::budget;
+use aoc_common::parse;
+use aoc_common::{debug, Answer, AocError, Result, Solution};
+
+pub struct Day20 {
+    numbers: Vec<isize>,
+}
+
+impl Solution for Day20 {
+    fn parse(input: &str) -> Result<Day20> {
+        let numbers = parse::lines(input)
+            .map(|mut line| {
+                let n = line.number::<isize>()?;
+                line.end()?;
+                Ok(n)
+            })
+            .collect::<Result<Vec<isize>>>()?;
+        if !numbers.contains(&0) {
+            return Err(AocError::parse("none of the numbers is 0"));
+        }
+        Ok(Day20 { numbers })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        let orig = self
+            .numbers
+            .iter()
+            .map(|k| {
+                k.checked_mul(811_589_153_isize)
+                    .ok_or_else(|| AocError::no_solution(format!("{} is too big to decrypt", k)))
+            })
+            .collect::<Result<Vec<isize>>>()?;
+        let mut mixing = (0..orig.len()).collect::<Vec<usize>>();
+
+        // A single number has nowhere to move to.
+        let rounds = if orig.len() > 1 { 10 } else { 0 };
+        for round in 0..rounds {
+            budget::progress(format!("round {} of 10", round + 1));
+            for n in 0..orig.len() {
+                budget::check()?;
+                let ixof: usize = mixing.iter().position(|&itm| itm == n).unwrap();
+                let number: isize = orig[n]; // number means the number
+                assert_eq!(mixing.remove(ixof), n); // if the removed value isn't what we expect, error
+                mixing.insert(
+                    (ixof as isize + number - 1).rem_euclid((orig.len() as isize) - 1_isize)
+                        as usize
+                        + 1,
+                    n,
+                );
+            }
+        }
+
+        // apply the mixing
+        let mixed = mixing
+            .iter()
+            .map(|index| orig[*index])
+            .collect::<Vec<isize>>();
+        let index_of_zero = mixed
+            .iter()
+            .position(|&mx| mx == 0)
+            .ok_or_else(|| AocError::parse("none of the numbers is 0"))?;
+        let coords = [
+            mixed[(index_of_zero + 1000).rem_euclid(mixed.len())],
+            mixed[(index_of_zero + 2000).rem_euclid(mixed.len())],
+            mixed[(index_of_zero + 3000).rem_euclid(mixed.len())],
+        ];
+
+        debug!("grove coordinates {:?}", coords);
+        Ok(coords.iter().sum::<isize>().into())
+    }
+}
@@ -1,28 +1,14 @@
+use aoc_common::{debug, trace, Answer, Result, Solution};
 use std::collections::HashMap;
-use std::env;
-use std::fmt;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
-
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
 
 type OpName = [char; 4];
 
 #[derive(Clone, Copy, Debug)]
 enum OpType {
-    MINUS,
-    PLUS,
-    MUL,
-    DIV,
+    Minus,
+    Plus,
+    Mul,
+    Div,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -49,7 +35,7 @@ fn parse_line(line: String) -> (OpName, OpOrNum) {
                 x,
                 OpOrNum {
                     op: Some(Op {
-                        op_type: OpType::PLUS,
+                        op_type: OpType::Plus,
                         operands: (to_opname(name0), to_opname(name1)),
                     }),
                     num: None,
@@ -60,7 +46,7 @@ fn parse_line(line: String) -> (OpName, OpOrNum) {
                 x,
                 OpOrNum {
                     op: Some(Op {
-                        op_type: OpType::MINUS,
+                        op_type: OpType::Minus,
                         operands: (to_opname(name0), to_opname(name1)),
                     }),
                     num: None,
@@ -71,7 +57,7 @@ fn parse_line(line: String) -> (OpName, OpOrNum) {
                 x,
                 OpOrNum {
                     op: Some(Op {
-                        op_type: OpType::MUL,
+                        op_type: OpType::Mul,
                         operands: (to_opname(name0), to_opname(name1)),
                     }),
                     num: None,
@@ -82,7 +68,7 @@ fn parse_line(line: String) -> (OpName, OpOrNum) {
                 x,
                 OpOrNum {
                     op: Some(Op {
-                        op_type: OpType::DIV,
+                        op_type: OpType::Div,
                         operands: (to_opname(name0), to_opname(name1)),
                     }),
                     num: None,
@@ -110,10 +96,10 @@ fn expand(name: OpName, data: &HashMap<OpName, OpOrNum>) -> f64 {
         num
     } else if let Some(op) = op_or_num.op {
         match op.op_type {
-            OpType::MUL => expand(op.operands.0, data) * expand(op.operands.1, data),
-            OpType::DIV => expand(op.operands.0, data) / expand(op.operands.1, data),
-            OpType::MINUS => expand(op.operands.0, data) - expand(op.operands.1, data),
-            OpType::PLUS => expand(op.operands.0, data) + expand(op.operands.1, data),
+            OpType::Mul => expand(op.operands.0, data) * expand(op.operands.1, data),
+            OpType::Div => expand(op.operands.0, data) / expand(op.operands.1, data),
+            OpType::Minus => expand(op.operands.0, data) - expand(op.operands.1, data),
+            OpType::Plus => expand(op.operands.0, data) + expand(op.operands.1, data),
         }
     } else {
         panic!("This shouldn't happen")
@@ -128,13 +114,13 @@ fn binary_search(
     max: f64,
     data: &mut HashMap<OpName, OpOrNum>,
 ) -> f64 {
-    println!("{:?}", (min, max));
+    trace!("humn between {} and {}", min, max);
     let mid_point: f64 = (min + max) / 2.0;
     *data.get_mut(&humn).unwrap() = OpOrNum {
         num: Some(mid_point),
         op: None,
     };
-    let e = expand(m0, &data);
+    let e = expand(m0, data);
     if e == target {
         mid_point
     } else if e > target {
@@ -144,39 +130,46 @@ fn binary_search(
     }
 }
 
-fn main() {
-    if let Some(arg1) = env::args().nth(1) {
-        // File hosts must exist in current path before this produces output
-        if let Ok(lines) = read_lines(arg1) {
-            // Consumes the iterator, returns an (Optional) String
-            let r: HashMap<_, _> = lines
-                .filter_map(|x| x.ok())
-                .collect::<Vec<String>>()
-                .into_iter()
-                .map(parse_line)
-                .collect();
+pub struct Day21 {
+    monkeys: HashMap<OpName, OpOrNum>,
+}
 
-            let root = to_opname("root");
-            let part_1 = expand(root, &r);
-            println!("part_1: {:?}", part_1);
+impl Solution for Day21 {
+    fn parse(input: &str) -> Result<Day21> {
+        Ok(Day21 {
+            monkeys: input
+                .lines()
+                .map(|line| parse_line(line.to_string()))
+                .collect(),
+        })
+    }
 
-            let mut data = r.clone();
+    fn part1(&self) -> Result<Answer> {
+        let root = to_opname("root");
+        let part_1 = expand(root, &self.monkeys);
+        Ok((part_1 as i64).into())
+    }
 
-            let humn = to_opname("humn");
+    fn part2(&self) -> Result<Answer> {
+        let r = &self.monkeys;
+        let root = to_opname("root");
 
-            let (m0, m1) = r.get(&root).unwrap().op.unwrap().operands;
+        let mut data = r.clone();
 
-            let target = expand(m1, &data);
+        let humn = to_opname("humn");
 
-            let part_2 = binary_search(humn, m0, target, 0.0, 10000000000000.0, &mut data);
+        let (m0, m1) = r.get(&root).unwrap().op.unwrap().operands;
 
-            println!("part_2: {:?}", part_2);
+        let target = expand(m1, &data);
 
-            println!("target: {}", target);
+        let part_2 = binary_search(humn, m0, target, 0.0, 10000000000000.0, &mut data);
 
-            assert_eq!(data.get(&humn).unwrap().num.unwrap(), part_2);
+        debug!("humn = {} makes both sides {}", part_2, target);
 
-            assert_eq!(expand(m0, &data), target)
-        }
+        assert_eq!(data.get(&humn).unwrap().num.unwrap(), part_2);
+
+        assert_eq!(expand(m0, &data), target);
+
+        Ok((part_2 as i64).into())
     }
 }
@@ -0,0 +1,3 @@
+fn main() {
+    aoc_common::main::<day_2022_21::Day21>()
+}
@@ -0,0 +1,360 @@
+use aoc_common::animate::{Animation, Colour, Frame};
+use aoc_common::geometry::{Direction, Point2};
+use aoc_common::image::{self, Image};
+use aoc_common::parse::Scanner;
+use aoc_common::{debug, trace, Answer, AocError, Grid, Result, Solution};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PosType {
+    /// Off the map, where the input has a space or nothing at all.
+    Void,
+    Empty,
+    Rock,
+}
+
+type Map = Grid<PosType>;
+type Point = Point2<isize>;
+type Edges = HashMap<(Point, Direction), Point>;
+/// Watches each step of a walk, and stops it by returning `false`.
+type Visit<'a> = dyn FnMut(&CurrentPos) -> bool + 'a;
+
+/// Directions in the order the password counts them, from right = 0.
+const FACINGS: [Direction; 4] = [
+    Direction::Right,
+    Direction::Down,
+    Direction::Left,
+    Direction::Up,
+];
+
+#[derive(Debug)]
+struct CurrentPos {
+    pos: Point,
+    direction: Direction,
+}
+
+impl CurrentPos {
+    fn score(&self) -> isize {
+        let facing = FACINGS.iter().position(|&d| d == self.direction).unwrap();
+        1000 * (self.pos.y + 1) + 4 * (self.pos.x + 1) + facing as isize
+    }
+
+    /// Moves up to `magnitude` steps, calling `visit` after each one. Returns `false`
+    /// as soon as `visit` does.
+    fn update_pos(
+        &mut self,
+        magnitude: isize,
+        map: &Map,
+        edges: &Edges,
+        visit: &mut Visit,
+    ) -> Result<bool> {
+        for _ in 0..magnitude {
+            let mut new_pos = self.pos + self.direction.delta();
+            if tile(map, new_pos).is_none() {
+                // We have to wrap
+                new_pos = *edges.get(&(new_pos, self.direction)).ok_or_else(|| {
+                    AocError::no_solution(format!(
+                        "no edge of the cube leads on from {} facing {:?}",
+                        self.pos, self.direction
+                    ))
+                })?;
+            }
+            match tile(map, new_pos) {
+                Some(PosType::Rock) => break,
+                Some(_) => self.pos = new_pos,
+                None => {}
+            }
+            if !visit(self) {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    /// Turns left for `L` and right for `R`, the only turns the parser lets through.
+    fn update_dir(&mut self, dir: char) {
+        self.direction = match dir {
+            'L' => self.direction.turn_left(),
+            _ => self.direction.turn_right(),
+        }
+    }
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::Up => '^',
+        Direction::Right => '>',
+        Direction::Down => 'v',
+        Direction::Left => '<',
+    }
+}
+
+/// The tile at `p`, or `None` off the map.
+fn tile(map: &Map, p: Point) -> Option<&PosType> {
+    let pos = (usize::try_from(p.x).ok()?, usize::try_from(p.y).ok()?);
+    map.get(pos).filter(|&&tile| tile != PosType::Void)
+}
+
+/// The first and last x of the tiles on row `y`, `None` if it has none.
+fn row_bounds(y: isize, map: &Map) -> Option<(isize, isize)> {
+    if y < 0 || y as usize >= map.height() {
+        return None;
+    }
+    let row = map.row(y as usize);
+    let min = row.iter().position(|&t| t != PosType::Void)?;
+    let max = row.iter().rposition(|&t| t != PosType::Void)?;
+    Some((min as isize, max as isize))
+}
+
+/// The top left open tile, where the walk starts.
+fn start(map: &Map) -> Result<Point> {
+    let (x, _) =
+        row_bounds(0, map).ok_or_else(|| AocError::parse("the map's first row is empty"))?;
+    Ok(Point::new(x, 0))
+}
+
+/// Reads the path: numbers of steps with an `L` or `R` turn between each two.
+fn parse_instructions(mut line: Scanner) -> Result<(Vec<isize>, Vec<char>)> {
+    let mut magnitudes = vec![line.number::<u32>()? as isize];
+    let mut directions = vec![];
+    while !line.at_end() {
+        let turn = line.one_of(&["L", "R"])?;
+        directions.push(if turn == "L" { 'L' } else { 'R' });
+        magnitudes.push(line.number::<u32>()? as isize);
+    }
+    Ok((magnitudes, directions))
+}
+
+fn parse_tile(c: char) -> Result<PosType> {
+    match c {
+        ' ' => Ok(PosType::Void),
+        '.' => Ok(PosType::Empty),
+        '#' => Ok(PosType::Rock),
+        _ => Err(AocError::parse(format!("{:?} is not a tile", c))),
+    }
+}
+
+fn cube_edges() -> Edges {
+    let mut edges: Edges = HashMap::new();
+    // From `(x, y)` moving in the `facing` direction, to `(to_x, to_y)`.
+    let mut edge = |x, y, facing: usize, to_x, to_y| {
+        edges.insert((Point::new(x, y), FACINGS[facing]), Point::new(to_x, to_y));
+    };
+
+    for i in 0..50 {
+        //i
+
+        //moving south
+        edge(100 + i, 50, 1, 99, 50 + i);
+
+        //moving east
+        edge(100, 50 + i, 0, 100 + i, 49);
+    }
+
+    for i in 0..50 {
+        //ii
+
+        // moving east
+        edge(150, i, 0, 99, 149 - i);
+
+        // moving east
+        edge(100, 100 + i, 0, 149, -i);
+    }
+
+    for i in 0..50 {
+        //iii
+
+        // moving south
+        edge(50 + i, 150, 1, 49, 150 + i);
+
+        // moving east
+        edge(50, 150 + i, 0, 50 + i, 149);
+    }
+
+    for i in 0..50 {
+        //iv
+
+        // moving north
+        edge(i, 99, 3, 50, 50 + i);
+
+        // moving west
+        edge(49, 50 + i, 2, i, 100);
+    }
+
+    for i in 0..50 {
+        //v
+
+        // moving west
+        edge(49, i, 2, 0, 149 - i);
+
+        //moving west
+        edge(-1, 100 + i, 2, 49, -i);
+    }
+
+    for i in 0..50 {
+        //vi
+
+        // moving north
+        edge(50 + i, -1, 3, 0, 150 + i);
+
+        // moving west
+        edge(-1, 150 + i, 2, 50 + i, 0);
+    }
+
+    for i in 0..50 {
+        //vii
+
+        //moving north
+        edge(100 + i, -1, 3, i, 199);
+
+        //move_south
+        edge(i, 200, 1, 100 + i, 0);
+    }
+
+    edges
+}
+
+pub struct Day22 {
+    map: Map,
+    /// How far to walk before each turn and after the last one.
+    magnitudes: Vec<isize>,
+    directions: Vec<char>,
+}
+
+impl Solution for Day22 {
+    fn parse(input: &str) -> Result<Day22> {
+        let raw = input
+            .lines()
+            .map(|line| line.to_string())
+            .collect::<Vec<String>>();
+        let map_lines = raw
+            .clone()
+            .into_iter()
+            .take_while(|line| !line.is_empty())
+            .collect::<Vec<String>>();
+
+        let instructions = raw.last().ok_or_else(|| AocError::parse("empty input"))?;
+        let (magnitudes, directions) = parse_instructions(Scanner::new(instructions, raw.len()))?;
+
+        // Lines stop at their last tile, so pad them out to a rectangle.
+        let width = map_lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let map = Grid::parse_with(
+            &map_lines
+                .iter()
+                .map(|line| format!("{:width$}\n", line, width = width))
+                .collect::<String>(),
+            parse_tile,
+        )?;
+
+        start(&map)?;
+        Ok(Day22 {
+            map,
+            magnitudes,
+            directions,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Ok(self.walk(&self.cube()?, &mut |_| true)?.into())
+    }
+
+    /// The cube net with the path walked over it. The start is green, the end red.
+    fn image(&self) -> Result<Image> {
+        let edges = self.cube()?;
+        let mut image = Image::from_grid(&self.map, |tile| match tile {
+            PosType::Void => image::BLACK,
+            PosType::Empty => [40, 40, 40],
+            PosType::Rock => image::GREY,
+        });
+        let mut last = start(&self.map)?;
+        image.set(last.x as usize, last.y as usize, image::GREEN);
+        self.walk(&edges, &mut |current| {
+            last = current.pos;
+            image.set(last.x as usize, last.y as usize, image::CYAN);
+            true
+        })?;
+        image.set(last.x as usize, last.y as usize, image::RED);
+        Ok(image.scale(3))
+    }
+
+    /// The walk over the cube.
+    fn animate(&self, animation: &mut Animation) -> Result<()> {
+        let edges = self.cube()?;
+        let mut frame = self.draw();
+        let mut last: Option<Point> = None;
+        let mut steps = 0;
+        self.walk(&edges, &mut |current| {
+            steps += 1;
+            if let Some(last) = last {
+                frame.set(
+                    last.x as i64,
+                    last.y as i64,
+                    arrow(current.direction),
+                    Colour::Cyan,
+                );
+            }
+            let p = current.pos;
+            frame.set(p.x as i64, p.y as i64, '@', Colour::Red);
+            frame.focus(p.x as i64, p.y as i64);
+            frame.caption(format!("step {} at {}", steps, p));
+            last = Some(p);
+            animation.show(&frame)
+        })?;
+        Ok(())
+    }
+}
+
+impl Day22 {
+    /// How the edges of the map join up once folded into a cube, which is only
+    /// known for the layout of the real input.
+    fn cube(&self) -> Result<Edges> {
+        if self.map.iter().filter(|(_, &t)| t != PosType::Void).count() != 6 * 50 * 50 {
+            return Err(AocError::no_solution(
+                "the cube folding is only known for 50x50 faces",
+            ));
+        }
+        Ok(cube_edges())
+    }
+
+    /// The open tiles and the rocks.
+    fn draw(&self) -> Frame {
+        let mut frame = Frame::new();
+        for ((x, y), tile) in self.map.iter() {
+            match tile {
+                PosType::Void => {}
+                PosType::Empty => frame.set(x as i64, y as i64, '.', Colour::Grey),
+                PosType::Rock => frame.set(x as i64, y as i64, '#', Colour::White),
+            }
+        }
+        frame
+    }
+
+    /// Follows the instructions from the top left open tile, wrapping off the map
+    /// through `edges`, and returns the final password. `visit` sees every step.
+    fn walk(&self, edges: &Edges, visit: &mut Visit) -> Result<isize> {
+        let map = &self.map;
+
+        let mut pos = CurrentPos {
+            pos: start(map)?,
+            direction: Direction::Right,
+        };
+
+        let mut directions_iter = self.directions.iter();
+        for &magnitude in &self.magnitudes {
+            if !pos.update_pos(magnitude, map, edges, visit)? {
+                break;
+            }
+            if let Some(d) = directions_iter.next() {
+                trace!("at {} facing {:?}, turning {}", pos.pos, pos.direction, d);
+                pos.update_dir(*d);
+            }
+        }
+
+        debug!("finished at {} facing {:?}", pos.pos, pos.direction);
+        Ok(pos.score())
+    }
+}
@@ -0,0 +1,224 @@
+//! Timing history for `aoc bench`. Every run appends one line per day and stage to
+//! `bench_history.csv` at the workspace root:
+//!
+//! ```text
+//! recorded,day,stage,median_us,spread_us,runs
+//! 1671746454,15,part1,1234567,2345,10
+//! ```
+//!
+//! A stage has regressed when its median is more than the threshold slower than the
+//! last time it was recorded. Stages faster than [`NOISE_FLOOR_US`] are never
+//! flagged, since a few microseconds either way is only noise.
+
+use aoc_common::{AocError, Result, Timings};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_FILE: &str = "bench_history.csv";
+
+const HEADER: &str = "recorded,day,stage,median_us,spread_us,runs";
+
+/// Stages whose median stays below this many microseconds are not compared.
+pub const NOISE_FLOOR_US: u64 = 1000;
+
+/// The stages of solving a day that are timed separately.
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+pub fn stage_times(timings: &Timings) -> [Duration; 3] {
+    [timings.parse, timings.part1, timings.part2]
+}
+
+/// The median of some timings and their spread, the median distance from it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub median: Duration,
+    pub spread: Duration,
+}
+
+fn median(sorted: &[Duration]) -> Duration {
+    let middle = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[middle - 1] + sorted[middle]) / 2
+    } else {
+        sorted[middle]
+    }
+}
+
+/// Summarises at least one timing.
+pub fn summarize(samples: &[Duration]) -> Summary {
+    let mut sorted = samples.to_vec();
+    sorted.sort();
+    let middle = median(&sorted);
+    let mut deviations: Vec<Duration> = sorted
+        .iter()
+        .map(|&sample| sample.max(middle) - sample.min(middle))
+        .collect();
+    deviations.sort();
+    Summary {
+        median: middle,
+        spread: median(&deviations),
+    }
+}
+
+/// One line of the history file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    /// Seconds since the Unix epoch.
+    pub recorded: u64,
+    pub day: u8,
+    pub stage: String,
+    pub median_us: u64,
+    pub spread_us: u64,
+    pub runs: usize,
+}
+
+impl Record {
+    pub fn new(day: u8, stage: &str, summary: Summary, runs: usize) -> Record {
+        Record {
+            recorded: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            day,
+            stage: stage.to_string(),
+            median_us: summary.median.as_micros() as u64,
+            spread_us: summary.spread.as_micros() as u64,
+            runs,
+        }
+    }
+
+    fn to_line(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.recorded, self.day, self.stage, self.median_us, self.spread_us, self.runs
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Record> {
+        let fields: Vec<&str> = line.split(',').collect();
+        match fields[..] {
+            [recorded, day, stage, median_us, spread_us, runs] => Some(Record {
+                recorded: recorded.parse().ok()?,
+                day: day.parse().ok()?,
+                stage: stage.to_string(),
+                median_us: median_us.parse().ok()?,
+                spread_us: spread_us.parse().ok()?,
+                runs: runs.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Every record in the history file, oldest first; none if there is no file yet.
+pub fn load_history(path: &Path) -> Result<Vec<Record>> {
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let contents = aoc_common::input::read_to_string(path)?;
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| *line != HEADER && !line.trim().is_empty())
+        .map(|(i, line)| {
+            Record::from_line(line)
+                .ok_or_else(|| AocError::parse(format!("{}:{}: bad record", path.display(), i + 1)))
+        })
+        .collect()
+}
+
+pub fn append_history(path: &Path, records: &[Record]) -> Result<()> {
+    let io_error = |source| AocError::Io {
+        path: Some(path.to_path_buf()),
+        source,
+    };
+    let new_file = !path.exists() || fs::metadata(path).map_err(io_error)?.len() == 0;
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(io_error)?;
+    let mut lines = String::new();
+    if new_file {
+        lines.push_str(HEADER);
+        lines.push('\n');
+    }
+    for record in records {
+        lines.push_str(&record.to_line());
+        lines.push('\n');
+    }
+    file.write_all(lines.as_bytes()).map_err(io_error)
+}
+
+/// The most recently recorded median of every day and stage.
+pub fn last_medians(history: &[Record]) -> HashMap<(u8, String), u64> {
+    history
+        .iter()
+        .map(|record| ((record.day, record.stage.clone()), record.median_us))
+        .collect()
+}
+
+/// Whether `current` is more than `threshold` percent slower than `previous`.
+pub fn regressed(previous_us: u64, current_us: u64, threshold: f64) -> bool {
+    current_us >= NOISE_FLOOR_US
+        && current_us as f64 > previous_us as f64 * (1.0 + threshold / 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn summary_ignores_outliers() {
+        assert_eq!(
+            summarize(&[ms(10), ms(500), ms(12), ms(11), ms(9)]),
+            Summary {
+                median: ms(11),
+                spread: ms(1),
+            }
+        );
+        assert_eq!(summarize(&[ms(4), ms(2)]).median, ms(3));
+    }
+
+    #[test]
+    fn flags_only_slowdowns_past_the_threshold() {
+        assert!(regressed(10_000, 11_500, 10.0));
+        assert!(!regressed(10_000, 10_900, 10.0));
+        assert!(!regressed(10_000, 5_000, 10.0));
+        assert!(!regressed(10, 900, 10.0));
+    }
+
+    #[test]
+    fn history_round_trips_and_keeps_the_latest_run() {
+        let path = env::temp_dir().join(format!("aoc_bench_history_{}.csv", std::process::id()));
+        let record = |median_us| Record {
+            recorded: 1,
+            day: 15,
+            stage: "part1".to_string(),
+            median_us,
+            spread_us: 3,
+            runs: 10,
+        };
+        append_history(&path, &[record(100)]).unwrap();
+        append_history(&path, &[record(200)]).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        let history = load_history(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents.matches(HEADER).count(), 1);
+        let history = history.unwrap();
+        assert_eq!(history, vec![record(100), record(200)]);
+        assert_eq!(
+            last_medians(&history).get(&(15, "part1".to_string())),
+            Some(&200)
+        );
+    }
+}
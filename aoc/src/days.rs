@@ -0,0 +1,37 @@
+use aoc_common::Day;
+
+/// The year the runner works on when no `--year` is given.
+pub const DEFAULT_YEAR: u32 = 2022;
+
+/// Every solved day, by year and then in order. `aoc_helpers new` adds to this list.
+pub const DAYS: &[Day] = &[
+    Day::new::<day_2022_01::Day01>(2022, 1),
+    Day::new::<day_2022_02::Day02>(2022, 2),
+    Day::new::<day_2022_03::Day03>(2022, 3),
+    Day::new::<day_2022_04::Day04>(2022, 4),
+    Day::new::<day_2022_05::Day05>(2022, 5),
+    Day::new::<day_2022_06::Day06>(2022, 6),
+    Day::new::<day_2022_07::Day07>(2022, 7),
+    Day::new::<day_2022_08::Day08>(2022, 8),
+    Day::new::<day_2022_09::Day09>(2022, 9),
+    Day::new::<day_2022_10::Day10>(2022, 10),
+    Day::new::<day_2022_11::Day11>(2022, 11),
+    Day::new::<day_2022_12::Day12>(2022, 12),
+    Day::new::<day_2022_13::Day13>(2022, 13),
+    Day::new::<day_2022_14::Day14>(2022, 14),
+    Day::new::<day_2022_15::Day15>(2022, 15),
+    Day::new::<day_2022_16::Day16>(2022, 16),
+    Day::new::<day_2022_19::Day19>(2022, 19),
+    Day::new::<day_2022_20::Day20>(2022, 20),
+    Day::new::<day_2022_21::Day21>(2022, 21),
+    Day::new::<day_2022_22::Day22>(2022, 22),
+];
+
+/// The solved days of `year`, in order.
+pub fn in_year(year: u32) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
+
+pub fn find(year: u32, number: u8) -> Option<&'static Day> {
+    in_year(year).find(|day| day.number == number)
+}
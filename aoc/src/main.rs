@@ -0,0 +1,637 @@
+use aoc_common::animate::{Animation, Options, DEFAULT_FPS};
+use aoc_common::budget::{self, CountingAllocator, Limits};
+use aoc_common::cache::{Cache, Entry};
+use aoc_common::{
+    differential, info, log, parallel, record, regression, warn, Answer, AocError, Day,
+    InputSource, Part, Result,
+};
+use clap::{Args, Parser, Subcommand};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+mod bench;
+mod days;
+mod watch;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser, Debug)]
+/// Run the Advent of Code solutions in this workspace.
+#[clap(author = "Oliver Sargent", long_about=None)]
+struct Arguments {
+    #[clap(subcommand)]
+    command: Command,
+    /// log more about what the solutions are doing, repeat for more detail
+    #[clap(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+    /// only log errors
+    #[clap(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// write the log to this file instead of stderr
+    #[clap(long, global = true)]
+    log_file: Option<PathBuf>,
+    /// threads for the days that split up their work, 0 for one per CPU
+    #[clap(short = 'j', long, global = true, default_value_t = 0)]
+    threads: usize,
+    /// year whose days to work on
+    #[clap(short, long, global = true, default_value_t = days::DEFAULT_YEAR)]
+    year: u32,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Solve one day, or every day with --all
+    Run(RunArguments),
+    /// Time parsing and each part, and compare with the last recorded timings
+    Bench(BenchArguments),
+    /// Compare solutions with their slow reference versions on random inputs
+    Check(CheckArguments),
+    /// Check a day's recorded answers, on the examples first and then the real input
+    Test(TestArguments),
+    /// Test a day again whenever its code, examples or input change
+    Watch(WatchArguments),
+}
+
+#[derive(Args, Debug)]
+struct RunArguments {
+    /// day to run
+    #[clap(short, long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// part to run, both when left out
+    #[clap(short, long)]
+    part: Option<u8>,
+    /// input file to use instead of the cached input, `-` for stdin
+    #[clap(short, long)]
+    input: Option<String>,
+    /// run every day and print a table of the answers
+    #[clap(short, long)]
+    all: bool,
+    /// play the day's simulation in the terminal instead of printing answers
+    #[clap(long, conflicts_with = "all")]
+    animate: bool,
+    /// frames per second when animating, 0 for as fast as possible
+    #[clap(long, default_value_t = DEFAULT_FPS, requires = "animate")]
+    fps: f64,
+    /// keep the moving part of the animation in the middle of the screen
+    #[clap(long, requires = "animate")]
+    follow: bool,
+    /// start the animation paused, to step through it with enter
+    #[clap(long, requires = "animate")]
+    paused: bool,
+    /// save a picture of the solved puzzle to this .png or .ppm file
+    #[clap(long, conflicts_with_all = &["all", "animate"])]
+    export_image: Option<PathBuf>,
+    /// print answers as text, or as one JSON record per day and part
+    #[clap(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// seconds each day may run before it is stopped, except when animating
+    #[clap(long)]
+    time_limit: Option<f64>,
+    /// MiB each day may allocate before it is stopped
+    #[clap(long)]
+    memory_limit: Option<usize>,
+}
+
+impl RunArguments {
+    fn limits(&self) -> Limits {
+        Limits {
+            time: self
+                .time_limit
+                .map(|seconds| Duration::from_secs_f64(seconds.max(0.0))),
+            memory: self.memory_limit.map(|mib| mib.saturating_mul(1 << 20)),
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Args, Debug)]
+struct BenchArguments {
+    /// day to time, every day when left out
+    #[clap(short, long)]
+    day: Option<u8>,
+    /// how many times to solve each input
+    #[clap(short, long, default_value_t = 10)]
+    runs: usize,
+    /// percentage a median may grow by before it counts as a regression
+    #[clap(short, long, default_value_t = 10.0)]
+    threshold: f64,
+    /// only report, without adding to the history file
+    #[clap(long)]
+    no_record: bool,
+}
+
+#[derive(Args, Debug)]
+struct CheckArguments {
+    /// day to check, every day with a reference solution when left out
+    #[clap(short, long)]
+    day: Option<u8>,
+    /// how many random inputs to try on each day
+    #[clap(short, long, default_value_t = 100)]
+    cases: usize,
+    /// seed for the random inputs, to repeat an earlier check
+    #[clap(short, long)]
+    seed: Option<u64>,
+}
+
+#[derive(Args, Debug)]
+struct TestArguments {
+    /// day to test
+    #[clap(short, long)]
+    day: u8,
+}
+
+#[derive(Args, Debug)]
+struct WatchArguments {
+    /// day to watch
+    #[clap(short, long)]
+    day: u8,
+    /// milliseconds between looks at the files
+    #[clap(long, default_value_t = 500)]
+    interval: u64,
+}
+
+/// The workspace root, where the `<year>/day_XX` directories live.
+fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+/// The day's real input, as fetched into the cache.
+fn default_input(day: &Day) -> PathBuf {
+    Cache::in_workspace(repo_root()).path(day.year, day.number, Entry::Input)
+}
+
+/// The input named on the command line, or the day's cached input.
+fn input_source(day: &Day, input: Option<String>) -> InputSource {
+    match input {
+        Some(arg) => InputSource::from_arg(Some(arg)),
+        None => InputSource::Path(default_input(day)),
+    }
+}
+
+fn find_day(year: u32, number: u8) -> Result<&'static Day> {
+    days::find(year, number)
+        .ok_or_else(|| AocError::usage(format!("day {} of {} is not solved", number, year)))
+}
+
+fn parse_parts(part: Option<u8>) -> Result<Vec<Part>> {
+    match part {
+        None => Ok(Part::BOTH.to_vec()),
+        Some(n) => Part::from_number(n)
+            .map(|part| vec![part])
+            .ok_or_else(|| AocError::usage(format!("there is no part {}", n))),
+    }
+}
+
+/// Runs `f` for the day under `limits`, logging the peak memory when it is known.
+fn within_budget<T>(day: &Day, limits: Limits, f: impl FnOnce() -> T) -> T {
+    let _watchdog = budget::enforce(&format!("day {}", day.number), limits);
+    let result = f();
+    if let Some(peak) = budget::peak_memory() {
+        info!(
+            "day {}: peak memory {}",
+            day.number,
+            budget::format_bytes(peak)
+        );
+    }
+    result
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    input: Option<String>,
+    format: Format,
+    limits: Limits,
+) -> Result<()> {
+    let source = input_source(day, input);
+    let input = source.read_to_string()?;
+    if format == Format::Json {
+        for record in within_budget(day, limits, || record::solve(day, &input, parts)) {
+            println!("{}", record.to_json());
+        }
+        return Ok(());
+    }
+    let answers = within_budget(day, limits, || (day.solve)(&input, parts))
+        .map_err(|e| e.in_file(source.path()))?;
+    for (part, answer) in parts.iter().zip(answers) {
+        match answer {
+            Ok(answer) => println!("{}", answer),
+            Err(AocError::Unsolved) => warn!("part {}: {}", part.number(), AocError::Unsolved),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+/// Only the memory limit applies, as an animation runs for as long as it is
+/// watched.
+fn animate_day(day: &Day, input: Option<String>, options: Options, limits: Limits) -> Result<()> {
+    let source = input_source(day, input);
+    if source == InputSource::Stdin {
+        return Err(AocError::usage(
+            "an animation is controlled from stdin, so its input must be a file",
+        ));
+    }
+    let input = source.read_to_string()?;
+    let mut animation = Animation::new(options);
+    let limits = Limits {
+        time: None,
+        ..limits
+    };
+    within_budget(day, limits, || (day.animate)(&input, &mut animation))
+        .map_err(|e| e.in_file(source.path()))?;
+    info!("showed {} frames", animation.shown());
+    Ok(())
+}
+
+fn export_image(day: &Day, input: Option<String>, path: &Path, limits: Limits) -> Result<()> {
+    let source = input_source(day, input);
+    let input = source.read_to_string()?;
+    let image =
+        within_budget(day, limits, || (day.image)(&input)).map_err(|e| e.in_file(source.path()))?;
+    image.save(path)?;
+    info!(
+        "saved a {}x{} picture to {}",
+        image.width(),
+        image.height(),
+        path.display()
+    );
+    Ok(())
+}
+
+fn cell(answer: &Result<Answer>) -> String {
+    match answer {
+        Ok(answer) => answer.to_string(),
+        Err(AocError::Unsolved) => "-".to_string(),
+        Err(e) => format!("error: {}", e),
+    }
+}
+
+/// One JSON record per part for every day with an input.
+fn run_all_json(year: u32, limits: Limits) {
+    for day in days::in_year(year) {
+        match InputSource::Path(default_input(day)).read_to_string() {
+            Ok(input) => {
+                let records =
+                    within_budget(day, limits, || record::solve(day, &input, &Part::BOTH));
+                for record in records {
+                    println!("{}", record.to_json());
+                }
+            }
+            Err(e) => info!("day {}: {}", day.number, e),
+        }
+    }
+}
+
+fn run_all(year: u32, limits: Limits) {
+    let mut rows: Vec<[String; 3]> = vec![];
+    for day in days::in_year(year) {
+        let path = default_input(day);
+        let cells = match InputSource::Path(path.clone()).read_to_string() {
+            Ok(input) => match within_budget(day, limits, || (day.solve)(&input, &Part::BOTH)) {
+                Ok(answers) => [cell(&answers[0]), cell(&answers[1])],
+                Err(e) => [format!("error: {}", e.in_file(Some(&path))), String::new()],
+            },
+            Err(_) => ["no input".to_string(), String::new()],
+        };
+        // Multi-line answers (like a CRT screen) continue on the following rows.
+        let first: Vec<&str> = cells[0].lines().collect();
+        let second: Vec<&str> = cells[1].lines().collect();
+        for i in 0..first.len().max(second.len()).max(1) {
+            rows.push([
+                if i == 0 {
+                    day.number.to_string()
+                } else {
+                    String::new()
+                },
+                first.get(i).unwrap_or(&"").to_string(),
+                second.get(i).unwrap_or(&"").to_string(),
+            ]);
+        }
+    }
+
+    let header = [
+        "day".to_string(),
+        "part 1".to_string(),
+        "part 2".to_string(),
+    ];
+    let widths: Vec<usize> = (0..3)
+        .map(|i| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let print_row = |row: &[String; 3]| {
+        println!(
+            "{:>w0$} | {:<w1$} | {}",
+            row[0],
+            row[1],
+            row[2],
+            w0 = widths[0],
+            w1 = widths[1]
+        )
+    };
+    print_row(&header);
+    println!(
+        "{}-+-{}-+-{}",
+        "-".repeat(widths[0]),
+        "-".repeat(widths[1]),
+        "-".repeat(widths[2])
+    );
+    rows.iter().for_each(print_row);
+}
+
+fn run(year: u32, args: RunArguments) -> Result<()> {
+    if args.all {
+        match args.format {
+            Format::Text => run_all(year, args.limits()),
+            Format::Json => run_all_json(year, args.limits()),
+        }
+        return Ok(());
+    }
+    let day = find_day(year, args.day.expect("clap requires --day without --all"))?;
+    let limits = args.limits();
+    if args.animate {
+        let options = Options {
+            fps: args.fps.max(0.0),
+            follow: args.follow,
+            paused: args.paused,
+            ..Options::default()
+        };
+        return animate_day(day, args.input, options, limits);
+    }
+    if let Some(path) = &args.export_image {
+        return export_image(day, args.input, path, limits);
+    }
+    run_day(
+        day,
+        &parse_parts(args.part)?,
+        args.input,
+        args.format,
+        limits,
+    )
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+/// Times the chosen days, prints a table and returns how many stages regressed.
+fn bench(year: u32, args: BenchArguments) -> Result<usize> {
+    if cfg!(debug_assertions) {
+        warn!("timing a debug build, use `cargo run --release` for real numbers");
+    }
+    let days: Vec<&Day> = match args.day {
+        Some(number) => vec![find_day(year, number)?],
+        None => days::in_year(year).collect(),
+    };
+    let history_path = repo_root().join(bench::HISTORY_FILE);
+    let previous = bench::last_medians(&bench::load_history(&history_path)?);
+    let runs = args.runs.max(1);
+
+    let mut records = vec![];
+    let mut regressions = 0;
+    println!("day | stage |     median |     spread |   previous");
+    println!("----+-------+------------+------------+-----------");
+    for day in days {
+        let input = match InputSource::Path(default_input(day)).read_to_string() {
+            Ok(input) => input,
+            Err(_) => {
+                println!("{:>3} | no input", day.number);
+                continue;
+            }
+        };
+        let mut samples: [Vec<Duration>; 3] = Default::default();
+        for _ in 0..runs {
+            let timings = (day.time)(&input)?;
+            for (stage, time) in samples.iter_mut().zip(bench::stage_times(&timings)) {
+                stage.push(time);
+            }
+        }
+        for (stage, samples) in bench::STAGES.iter().zip(&samples) {
+            let summary = bench::summarize(samples);
+            let record = bench::Record::new(day.number, stage, summary, runs);
+            let last = previous.get(&(day.number, stage.to_string()));
+            let verdict = match last {
+                Some(&last) if bench::regressed(last, record.median_us, args.threshold) => {
+                    regressions += 1;
+                    format!(
+                        "  REGRESSED +{:.0}%",
+                        (record.median_us as f64 / last.max(1) as f64 - 1.0) * 100.0
+                    )
+                }
+                _ => String::new(),
+            };
+            println!(
+                "{:>3} | {:<5} | {:>10} | {:>10} | {:>10}{}",
+                day.number,
+                stage,
+                format_duration(summary.median),
+                format!("±{}", format_duration(summary.spread)),
+                last.map_or("-".to_string(), |&us| {
+                    format_duration(Duration::from_micros(us))
+                }),
+                verdict
+            );
+            records.push(record);
+        }
+    }
+    if !args.no_record {
+        bench::append_history(&history_path, &records)?;
+    }
+    Ok(regressions)
+}
+
+/// Where a day's shrunk counterexample is saved, ready for `aoc run -i`.
+fn counterexample_path(day: &Day) -> PathBuf {
+    repo_root().join(day.dir()).join("counterexample")
+}
+
+/// Checks the chosen days against their references and returns how many disagreed.
+fn check(year: u32, args: CheckArguments) -> Result<usize> {
+    let days: Vec<&Day> = match args.day {
+        Some(number) => vec![find_day(year, number)?],
+        None => days::in_year(year).collect(),
+    };
+    let seed = args.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_nanos() as u64)
+    });
+    println!("checking with seed {}", seed);
+    let options = differential::Options {
+        cases: args.cases,
+        seed,
+    };
+    let mut disagreements = 0;
+    for day in days {
+        let report = match differential::run(day.generate, day.reference, day.solve, &options) {
+            Ok(report) => report,
+            Err(AocError::Usage(_)) if args.day.is_none() => continue,
+            Err(e) => return Err(e),
+        };
+        info!(
+            "day {}: {} generated inputs rejected by the reference",
+            day.number, report.skipped
+        );
+        match report.counterexample {
+            None => println!("day {:>2}: {} inputs agree", day.number, report.checked),
+            Some(counterexample) => {
+                disagreements += 1;
+                let path = counterexample_path(day);
+                fs::write(&path, &counterexample.input).map_err(|source| AocError::Io {
+                    path: Some(path.clone()),
+                    source,
+                })?;
+                println!(
+                    "day {:>2}: input {} disagrees, shrunk to {} lines in {}\n{}",
+                    day.number,
+                    counterexample.case,
+                    counterexample.input.lines().count(),
+                    path.display(),
+                    counterexample
+                );
+            }
+        }
+    }
+    Ok(disagreements)
+}
+
+/// Checks each of the day's fixtures in turn, printing a line per fixture and the
+/// answers that changed. Returns whether they all held.
+fn test(year: u32, args: TestArguments) -> Result<bool> {
+    let day = find_day(year, args.day)?;
+    let dir = repo_root().join(day.dir());
+    let fixtures = regression::load(&dir, &default_input(day))?;
+    if fixtures.is_empty() {
+        warn!(
+            "{} has no {} to test against",
+            day.crate_name(),
+            regression::ANSWERS_FILE
+        );
+    }
+    let mut all_held = true;
+    for fixture in &fixtures {
+        let name = fixture
+            .input
+            .strip_prefix(repo_root())
+            .unwrap_or(&fixture.input);
+        let report = regression::check_fixture(fixture, day.solve)?;
+        if !report.skipped.is_empty() {
+            println!("skip {}: no such file", name.display());
+        } else if report.mismatches.is_empty() {
+            println!("pass {}: {} answers", name.display(), report.passed);
+        } else {
+            all_held = false;
+            for mismatch in &report.mismatches {
+                print!("FAIL {}", mismatch);
+            }
+        }
+    }
+    Ok(all_held)
+}
+
+fn main() {
+    let args = Arguments::parse();
+    log::set_level(log::Level::from_flags(args.verbose, args.quiet));
+    parallel::set_threads(args.threads);
+    if let Some(path) = &args.log_file {
+        if let Err(e) = log::log_to_file(path) {
+            eprint!("{}", e.report());
+            process::exit(1);
+        }
+    }
+    let result = match args.command {
+        Command::Run(run_args) => run(args.year, run_args),
+        Command::Bench(bench_args) => bench(args.year, bench_args).map(|regressions| {
+            if regressions > 0 {
+                eprintln!(
+                    "{} stage(s) got slower since the last recorded run",
+                    regressions
+                );
+                process::exit(1);
+            }
+        }),
+        Command::Test(test_args) => test(args.year, test_args).map(|all_held| {
+            if !all_held {
+                process::exit(1);
+            }
+        }),
+        Command::Watch(watch_args) => find_day(args.year, watch_args.day).and_then(|day| {
+            let roots = [repo_root().join(day.dir()), default_input(day)];
+            let interval = Duration::from_millis(watch_args.interval.max(1));
+            watch::watch(repo_root(), day, &roots, interval)
+        }),
+        Command::Check(check_args) => check(args.year, check_args).map(|disagreements| {
+            if disagreements > 0 {
+                process::exit(1);
+            }
+        }),
+    };
+    if let Err(e) = result {
+        eprint!("{}", e.report());
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Every day's `answers.toml` still holds, so refactors can't quietly change an answer.
+    #[test]
+    fn recorded_answers_still_hold() {
+        let mut failures = vec![];
+        for day in days::DAYS {
+            let dir = repo_root().join(day.dir());
+            match regression::check(&dir, &default_input(day), day.solve) {
+                Ok(report) => failures.extend(report.mismatches.iter().map(|m| m.to_string())),
+                Err(e) => failures.push(format!("{}: {}\n", dir.display(), e)),
+            }
+        }
+        assert!(
+            failures.is_empty(),
+            "recorded answers changed:\n{}",
+            failures.concat()
+        );
+    }
+
+    /// Every day with a reference solution agrees with it on a fixed set of inputs.
+    #[test]
+    fn solutions_agree_with_their_references() {
+        let options = differential::Options {
+            cases: 30,
+            seed: 2022,
+        };
+        let mut failures = vec![];
+        for day in days::DAYS {
+            match differential::run(day.generate, day.reference, day.solve, &options) {
+                Ok(report) => {
+                    assert!(report.checked > 0, "day {} checked nothing", day.number);
+                    if let Some(counterexample) = report.counterexample {
+                        failures.push(format!(
+                            "day {} on\n{}{}",
+                            day.number, counterexample.input, counterexample
+                        ));
+                    }
+                }
+                Err(AocError::Usage(_)) => {}
+                Err(e) => failures.push(format!("day {}: {}\n", day.number, e)),
+            }
+        }
+        assert!(failures.is_empty(), "{}", failures.concat());
+    }
+}
@@ -0,0 +1,133 @@
+//! `aoc watch`: polls a day's directory and its cached input, and whenever a file
+//! changes rebuilds the runner and checks the day's recorded answers with
+//! `aoc test`, examples first. The check runs in a fresh process through
+//! `cargo run`, so it always uses the code as it is on disk.
+
+use aoc_common::{AocError, Day, Result};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// When each watched file was last modified, and how long it was.
+pub type Snapshot = BTreeMap<PathBuf, (SystemTime, u64)>;
+
+/// Every file under each of `roots`, which may be files themselves. Roots that do
+/// not exist are left out, so a file appearing counts as a change.
+pub fn snapshot(roots: &[PathBuf]) -> Snapshot {
+    let mut files = Snapshot::new();
+    let mut pending: Vec<PathBuf> = roots.to_vec();
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else {
+            let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+            files.insert(path, (modified, metadata.len()));
+        }
+    }
+    files
+}
+
+/// A file that was added, changed or removed between two snapshots.
+pub fn first_change<'a>(before: &'a Snapshot, after: &'a Snapshot) -> Option<&'a Path> {
+    after
+        .iter()
+        .find(|&(path, stamp)| before.get(path) != Some(stamp))
+        .map(|(path, _)| path.as_path())
+        .or_else(|| {
+            before
+                .keys()
+                .find(|path| !after.contains_key(*path))
+                .map(PathBuf::as_path)
+        })
+}
+
+/// Rebuilds and runs `aoc test` for the day, with cargo's and the check's output
+/// going straight to the terminal. Returns whether every answer held.
+fn rebuild_and_test(workspace: &Path, day: &Day) -> Result<bool> {
+    let status = Command::new("cargo")
+        .current_dir(workspace)
+        .args(["run", "--quiet", "-p", "aoc", "--", "test", "--year"])
+        .arg(day.year.to_string())
+        .arg("--day")
+        .arg(day.number.to_string())
+        .status()
+        .map_err(|source| AocError::Io {
+            path: Some(PathBuf::from("cargo")),
+            source,
+        })?;
+    Ok(status.success())
+}
+
+/// Watches `roots` forever, checking `day` once at the start and again after each
+/// change. Changes that land while a check runs are picked up by the next poll.
+pub fn watch(workspace: &Path, day: &Day, roots: &[PathBuf], interval: Duration) -> Result<()> {
+    let mut last = snapshot(roots);
+    let mut reason = "starting".to_string();
+    loop {
+        println!("── day {}: {}, rebuilding ──", day.number, reason);
+        let verdict = if rebuild_and_test(workspace, day)? {
+            "all good"
+        } else {
+            "FAILED"
+        };
+        println!("── day {}: {}, waiting for changes ──", day.number, verdict);
+        loop {
+            thread::sleep(interval);
+            let now = snapshot(roots);
+            if let Some(path) = first_change(&last, &now) {
+                let path = path.strip_prefix(workspace).unwrap_or(path);
+                reason = format!("{} changed", path.display());
+                last = now;
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn snapshots_notice_new_changed_and_removed_files() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let src = dir.join("src");
+        fs::create_dir_all(&src).unwrap();
+        fs::write(src.join("lib.rs"), "fn a() {}").unwrap();
+        let roots = [dir.clone(), dir.join("missing_input")];
+
+        let before = snapshot(&roots);
+        assert_eq!(before.len(), 1);
+        assert_eq!(first_change(&before, &snapshot(&roots)), None);
+
+        fs::write(src.join("lib.rs"), "fn ab() {}").unwrap();
+        let changed = snapshot(&roots);
+        assert_eq!(
+            first_change(&before, &changed),
+            Some(src.join("lib.rs").as_path())
+        );
+
+        fs::write(dir.join("missing_input"), "1").unwrap();
+        let added = snapshot(&roots);
+        assert_eq!(
+            first_change(&changed, &added),
+            Some(dir.join("missing_input").as_path())
+        );
+
+        fs::remove_file(src.join("lib.rs")).unwrap();
+        assert_eq!(
+            first_change(&added, &snapshot(&roots)),
+            Some(src.join("lib.rs").as_path())
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,345 @@
+//! Playing a simulation back in the terminal. A day draws each step of its puzzle
+//! into a [`Frame`], a sparse picture of coloured characters, and hands it to an
+//! [`Animation`], which shows the part of it that fits on screen at a steady frame
+//! rate, optionally keeping a moving entity in the middle.
+//!
+//! Frames go to stderr so stdout keeps only answers. The animation is steered by
+//! typing a command and pressing enter: `p` pauses or resumes, an empty line pauses
+//! or steps one frame while paused, `+` and `-` double or halve the speed, and `q`
+//! stops early.
+
+use std::collections::HashMap;
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The eight basic ANSI colours, plus grey for things in the background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Colour {
+    #[default]
+    Plain,
+    Grey,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+impl Colour {
+    fn code(self) -> &'static str {
+        match self {
+            Colour::Plain => "\x1b[0m",
+            Colour::Grey => "\x1b[90m",
+            Colour::Red => "\x1b[31m",
+            Colour::Green => "\x1b[32m",
+            Colour::Yellow => "\x1b[33m",
+            Colour::Blue => "\x1b[34m",
+            Colour::Magenta => "\x1b[35m",
+            Colour::Cyan => "\x1b[36m",
+            Colour::White => "\x1b[97m",
+        }
+    }
+}
+
+/// One picture of the puzzle's state. Positions are `(x, y)` with `y` growing
+/// downwards and may be negative; anything not drawn is blank.
+#[derive(Debug, Clone, Default)]
+pub struct Frame {
+    cells: HashMap<(i64, i64), (char, Colour)>,
+    focus: Option<(i64, i64)>,
+    caption: String,
+}
+
+impl Frame {
+    pub fn new() -> Frame {
+        Frame::default()
+    }
+
+    /// Draws `c` at `(x, y)`, over whatever was there.
+    pub fn set(&mut self, x: i64, y: i64, c: char, colour: Colour) {
+        self.cells.insert((x, y), (c, colour));
+    }
+
+    /// Draws a line of text starting at `(x, y)`.
+    pub fn text(&mut self, x: i64, y: i64, text: &str, colour: Colour) {
+        for (i, c) in text.chars().enumerate() {
+            self.set(x + i as i64, y, c, colour);
+        }
+    }
+
+    /// The position to keep in view when following.
+    pub fn focus(&mut self, x: i64, y: i64) {
+        self.focus = Some((x, y));
+    }
+
+    /// A line shown under the picture, like the step number.
+    pub fn caption<S: Into<String>>(&mut self, caption: S) {
+        self.caption = caption.into();
+    }
+
+    /// The top left corner of everything drawn.
+    fn top_left(&self) -> (i64, i64) {
+        let x = self.cells.keys().map(|&(x, _)| x).min().unwrap_or(0);
+        let y = self.cells.keys().map(|&(_, y)| y).min().unwrap_or(0);
+        (x, y)
+    }
+}
+
+/// The speed animations play at unless told otherwise, and the speed `+` and `-`
+/// start from when frames are shown as fast as they come.
+pub const DEFAULT_FPS: f64 = 20.0;
+
+/// How an [`Animation`] plays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Options {
+    /// Frames per second, where 0 shows frames as fast as they come.
+    pub fps: f64,
+    /// Keep each frame's focus in the middle of the screen.
+    pub follow: bool,
+    /// Start paused, so the first frames can be stepped through.
+    pub paused: bool,
+    /// Columns and rows available for the picture.
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Options {
+    /// [`DEFAULT_FPS`] on a screen the size the shell reports, or 80 by 24.
+    fn default() -> Options {
+        let size = |var: &str, default: usize| {
+            env::var(var)
+                .ok()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(default)
+        };
+        Options {
+            fps: DEFAULT_FPS,
+            follow: false,
+            paused: false,
+            width: size("COLUMNS", 80),
+            // Leave room for the caption and the controls.
+            height: size("LINES", 24).saturating_sub(2).max(1),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Command {
+    Toggle,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Reads commands from stdin on a thread of its own, so frames keep coming while
+/// nobody types.
+fn read_commands() -> Receiver<Command> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines() {
+            let command = match line.as_deref().map(str::trim) {
+                Ok("") => Command::Step,
+                Ok("p") => Command::Toggle,
+                Ok("+") => Command::Faster,
+                Ok("-") => Command::Slower,
+                Ok("q") | Err(_) => Command::Quit,
+                Ok(_) => continue,
+            };
+            if sender.send(command).is_err() || command == Command::Quit {
+                break;
+            }
+        }
+    });
+    receiver
+}
+
+pub struct Animation {
+    options: Options,
+    out: Box<dyn Write>,
+    commands: Option<Receiver<Command>>,
+    shown: usize,
+    last_shown: Option<Instant>,
+}
+
+impl Animation {
+    /// An animation on stderr, controlled from stdin.
+    pub fn new(options: Options) -> Animation {
+        Animation {
+            options,
+            out: Box::new(io::stderr()),
+            commands: Some(read_commands()),
+            shown: 0,
+            last_shown: None,
+        }
+    }
+
+    /// An animation written to `out` that nobody controls, for tests and recordings.
+    pub fn to_writer<W: Write + 'static>(options: Options, out: W) -> Animation {
+        Animation {
+            options,
+            out: Box::new(out),
+            commands: None,
+            shown: 0,
+            last_shown: None,
+        }
+    }
+
+    /// How many frames have been shown so far.
+    pub fn shown(&self) -> usize {
+        self.shown
+    }
+
+    /// Shows `frame`, waiting for its turn, and returns whether to keep going:
+    /// `false` once asked to quit or when the terminal has gone away.
+    pub fn show(&mut self, frame: &Frame) -> bool {
+        let mut quit = false;
+        while let Some(command) = self.commands.as_ref().and_then(|c| c.try_recv().ok()) {
+            quit |= !self.obey(command);
+        }
+        if quit {
+            return false;
+        }
+
+        if let Some(last) = self.last_shown {
+            if self.options.fps > 0.0 && !self.options.paused {
+                let gap = Duration::from_secs_f64(1.0 / self.options.fps);
+                thread::sleep(gap.saturating_sub(last.elapsed()));
+            }
+        }
+        let screen = format!("\x1b[H\x1b[2J{}", self.render(frame));
+        if self.out.write_all(screen.as_bytes()).is_err() || self.out.flush().is_err() {
+            return false;
+        }
+        self.shown += 1;
+        self.last_shown = Some(Instant::now());
+
+        // While paused, wait here until told to step or carry on.
+        while self.options.paused {
+            let command = match &self.commands {
+                Some(commands) => commands.recv().unwrap_or(Command::Quit),
+                None => Command::Toggle,
+            };
+            if command == Command::Step {
+                break;
+            }
+            if !self.obey(command) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Applies a command, returning `false` for quit.
+    fn obey(&mut self, command: Command) -> bool {
+        match command {
+            Command::Toggle => self.options.paused = !self.options.paused,
+            Command::Step => self.options.paused = true,
+            // Frames shown as fast as they come have no speed to double or halve.
+            Command::Faster | Command::Slower if self.options.fps <= 0.0 => {
+                self.options.fps = DEFAULT_FPS
+            }
+            Command::Faster => self.options.fps *= 2.0,
+            Command::Slower => self.options.fps /= 2.0,
+            Command::Quit => return false,
+        }
+        true
+    }
+
+    /// The visible part of `frame` with its colours, the caption and a status line.
+    fn render(&self, frame: &Frame) -> String {
+        let Options { width, height, .. } = self.options;
+        let (left, top) = match frame.focus {
+            Some((x, y)) if self.options.follow => (x - width as i64 / 2, y - height as i64 / 2),
+            _ => frame.top_left(),
+        };
+        let mut screen = String::new();
+        let mut colour = Colour::Plain;
+        for y in top..top + height as i64 {
+            let mut row = String::new();
+            for x in left..left + width as i64 {
+                let (c, cell_colour) = frame.cells.get(&(x, y)).copied().unwrap_or((' ', colour));
+                if cell_colour != colour {
+                    row.push_str(cell_colour.code());
+                    colour = cell_colour;
+                }
+                row.push(c);
+            }
+            screen.push_str(row.trim_end());
+            screen.push('\n');
+        }
+        if colour != Colour::Plain {
+            screen.push_str(Colour::Plain.code());
+        }
+        let status = if self.options.paused {
+            "paused: enter steps, p resumes, q quits".to_string()
+        } else {
+            format!(
+                "frame {} at {} fps: enter pauses, +/- change speed, q quits",
+                self.shown + 1,
+                self.options.fps
+            )
+        };
+        format!("{}{}\n{}\n", screen, frame.caption, status)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(follow: bool) -> Options {
+        Options {
+            fps: 0.0,
+            follow,
+            paused: false,
+            width: 3,
+            height: 2,
+        }
+    }
+
+    #[test]
+    fn renders_the_visible_part_in_colour() {
+        let mut frame = Frame::new();
+        frame.text(-1, 5, "ab", Colour::Red);
+        frame.set(1, 6, '#', Colour::Plain);
+        frame.set(9, 9, 'x', Colour::Plain);
+        frame.caption("step 1");
+        let animation = Animation::to_writer(options(false), io::sink());
+        let screen = animation.render(&frame);
+        let mut lines = screen.lines();
+        assert_eq!(lines.next(), Some("\x1b[31mab"));
+        assert_eq!(lines.next(), Some("  \x1b[0m#"));
+        assert_eq!(lines.next(), Some("step 1"));
+        assert!(lines.next().unwrap().starts_with("frame 1"));
+    }
+
+    #[test]
+    fn follows_the_focus() {
+        let mut frame = Frame::new();
+        frame.set(0, 0, 'o', Colour::Plain);
+        frame.set(100, 50, '@', Colour::Plain);
+        frame.focus(100, 50);
+        let screen = Animation::to_writer(options(true), io::sink()).render(&frame);
+        assert_eq!(screen.lines().take(2).collect::<Vec<_>>(), ["", " @"]);
+        let mut animation = Animation::to_writer(options(true), io::sink());
+        assert!(animation.show(&frame) && animation.show(&frame));
+        assert_eq!(animation.shown(), 2);
+    }
+
+    #[test]
+    fn speeding_up_from_full_speed_picks_a_real_rate() {
+        let mut animation = Animation::to_writer(options(false), io::sink());
+        assert!(animation.obey(Command::Faster));
+        assert_eq!(animation.options.fps, DEFAULT_FPS);
+        assert!(animation.obey(Command::Faster));
+        assert_eq!(animation.options.fps, DEFAULT_FPS * 2.0);
+        assert!(animation.obey(Command::Slower) && animation.obey(Command::Slower));
+        assert_eq!(animation.options.fps, DEFAULT_FPS / 2.0);
+    }
+}
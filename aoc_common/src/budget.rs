@@ -0,0 +1,254 @@
+//! Time and memory budgets for a day, so a search that runs away on a bad input
+//! stops with a report instead of running for minutes or eating all the memory.
+//!
+//! The runner counts allocations with [`CountingAllocator`] and starts a
+//! [`Watchdog`] with [`enforce`] around each day. Once a limit is passed a flag
+//! goes up, raised by the allocation that went over the memory limit or by the
+//! watchdog once the time is up. Long loops pick it up by calling [`check`],
+//! which is a single atomic load while all is well, and they stop with
+//! [`AocError::OverBudget`]. Loops note how far they got with [`progress`], and
+//! that goes in the report.
+//!
+//! A thread can't be stopped from outside, so a day that never checks is a hard
+//! kill: [`GRACE`] after going over, the watchdog prints the same report and
+//! ends the whole process, without running destructors or the days after it.
+
+use crate::error::{AocError, Result};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::process;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Mutex;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How long a day gets to notice it went over before the watchdog exits the
+/// process, taking the rest of the run with it.
+pub const GRACE: Duration = Duration::from_secs(5);
+
+/// How often the watchdog looks at the clock.
+const POLL: Duration = Duration::from_millis(10);
+
+/// Values of [`OVER`]: which limit was passed, if any.
+const WITHIN: u8 = 0;
+const OVER_TIME: u8 = 1;
+const OVER_MEMORY: u8 = 2;
+
+/// Bytes currently allocated through [`CountingAllocator`].
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+/// The most ever allocated at once since the last [`reset_peak`].
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+/// Which limit has been passed, until the watchdog is dropped. An atomic rather
+/// than a message, as the allocator sets it and must not allocate.
+static OVER: AtomicU8 = AtomicU8::new(WITHIN);
+/// Bytes the allocator lets be in use before raising the flag.
+static MEMORY_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+/// The limits being enforced, for the report.
+static LIMITS: Mutex<Limits> = Mutex::new(Limits {
+    time: None,
+    memory: None,
+});
+/// The last thing passed to [`progress`].
+static PROGRESS: Mutex<Option<String>> = Mutex::new(None);
+
+/// The system allocator, keeping count of the bytes in use and the peak. Install
+/// it with `#[global_allocator]` in a binary to measure its memory.
+pub struct CountingAllocator;
+
+fn allocated(size: usize) {
+    let now = ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(now, Ordering::Relaxed);
+    if now > MEMORY_LIMIT.load(Ordering::Relaxed) {
+        go_over(OVER_MEMORY);
+    }
+}
+
+/// Raises the flag for `limit`, unless another limit got there first.
+fn go_over(limit: u8) {
+    let _ = OVER.compare_exchange(WITHIN, limit, Ordering::Relaxed, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// Bytes in use, `None` when [`CountingAllocator`] is not installed.
+pub fn memory_in_use() -> Option<usize> {
+    match ALLOCATED.load(Ordering::Relaxed) {
+        0 => None,
+        bytes => Some(bytes),
+    }
+}
+
+/// The most bytes in use at once since the last [`reset_peak`], `None` when
+/// [`CountingAllocator`] is not installed.
+pub fn peak_memory() -> Option<usize> {
+    match PEAK.load(Ordering::Relaxed) {
+        0 => None,
+        bytes => Some(bytes),
+    }
+}
+
+/// Starts measuring the peak again from what is in use now.
+pub fn reset_peak() {
+    PEAK.store(ALLOCATED.load(Ordering::Relaxed), Ordering::Relaxed);
+}
+
+/// `bytes` for people, like `12.5 MiB` or `340.0 KiB`.
+pub fn format_bytes(bytes: usize) -> String {
+    let kib = bytes as f64 / 1024.0;
+    if kib < 1024.0 {
+        format!("{:.1} KiB", kib)
+    } else {
+        format!("{:.1} MiB", kib / 1024.0)
+    }
+}
+
+/// What a day may use. `None` is no limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Limits {
+    pub time: Option<Duration>,
+    /// In bytes. Only enforced when [`CountingAllocator`] is installed.
+    pub memory: Option<usize>,
+}
+
+impl Limits {
+    pub fn is_unlimited(&self) -> bool {
+        self.time.is_none() && self.memory.is_none()
+    }
+}
+
+/// Notes how far the day has got, for the report if it goes over its budget.
+/// Called now and then rather than on every step, since it takes a lock.
+pub fn progress<S: Into<String>>(message: S) {
+    *PROGRESS.lock().unwrap_or_else(|e| e.into_inner()) = Some(message.into());
+}
+
+/// `Err` once the day has gone over one of its limits. Cheap enough to call on
+/// every step of a search.
+pub fn check() -> Result<()> {
+    if OVER.load(Ordering::Relaxed) == WITHIN {
+        Ok(())
+    } else {
+        Err(over_budget())
+    }
+}
+
+fn over_budget() -> AocError {
+    let limits = *LIMITS.lock().unwrap_or_else(|e| e.into_inner());
+    let reason = match (OVER.load(Ordering::Relaxed), limits) {
+        (
+            OVER_TIME,
+            Limits {
+                time: Some(time), ..
+            },
+        ) => format!("went over its time limit of {:?}", time),
+        (
+            OVER_MEMORY,
+            Limits {
+                memory: Some(memory),
+                ..
+            },
+        ) => format!("went over its memory limit of {}", format_bytes(memory)),
+        _ => "went over its budget".to_string(),
+    };
+    AocError::OverBudget {
+        reason,
+        progress: PROGRESS.lock().unwrap_or_else(|e| e.into_inner()).clone(),
+        peak_memory: peak_memory(),
+    }
+}
+
+/// Watches one day's budget from its own thread until dropped.
+pub struct Watchdog {
+    stop: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Starts watching `limits` for the work named `label`, from now until the
+/// returned watchdog is dropped. Also clears the progress and the peak memory, so
+/// both are about this piece of work alone.
+pub fn enforce(label: &str, limits: Limits) -> Watchdog {
+    reset_peak();
+    *PROGRESS.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    *LIMITS.lock().unwrap_or_else(|e| e.into_inner()) = limits;
+    MEMORY_LIMIT.store(limits.memory.unwrap_or(usize::MAX), Ordering::Relaxed);
+    if limits.is_unlimited() {
+        return Watchdog {
+            stop: None,
+            thread: None,
+        };
+    }
+    let label = label.to_string();
+    let (stop, stopped) = mpsc::channel::<()>();
+    let thread = thread::spawn(move || {
+        let start = Instant::now();
+        let mut over_since = None;
+        loop {
+            match stopped.recv_timeout(POLL) {
+                Err(RecvTimeoutError::Timeout) => {}
+                _ => return,
+            }
+            if let Some(since) = over_since {
+                if Instant::now().duration_since(since) >= GRACE {
+                    eprint!("{}", over_budget().report());
+                    eprintln!(
+                        "{} did not stop by itself, ending the run without the days after it",
+                        label
+                    );
+                    process::exit(1);
+                }
+                continue;
+            }
+            if limits.time.is_some_and(|time| start.elapsed() > time) {
+                go_over(OVER_TIME);
+            }
+            if OVER.load(Ordering::Relaxed) != WITHIN {
+                over_since = Some(Instant::now());
+            }
+        }
+    });
+    Watchdog {
+        stop: Some(stop),
+        thread: Some(thread),
+    }
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+        MEMORY_LIMIT.store(usize::MAX, Ordering::Relaxed);
+        OVER.store(WITHIN, Ordering::Relaxed);
+    }
+}
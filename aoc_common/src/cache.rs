@@ -0,0 +1,223 @@
+//! Downloaded inputs and puzzle pages, kept per year so several years can live in
+//! one repository:
+//!
+//! ```text
+//! cache/2022/day_05/input
+//! cache/2022/day_05/puzzle.html
+//! cache/2022/day_05/examples/
+//! cache/2022/day_05/meta.toml
+//! ```
+//!
+//! `meta.toml` records when each file was fetched, where from and a hash of its
+//! contents. Files put here by hand, like inputs kept from before there was a
+//! cache, have only the hash. Anything stored here is never fetched again.
+
+use crate::error::{AocError, Result};
+use crate::input::read_to_string;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The cache's directory, relative to the workspace root.
+pub const CACHE_DIR: &str = "cache";
+
+const META_FILE: &str = "meta.toml";
+
+/// A file the cache keeps for every day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Entry {
+    Input,
+    Puzzle,
+}
+
+impl Entry {
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Entry::Input => "input",
+            Entry::Puzzle => "puzzle.html",
+        }
+    }
+}
+
+/// Where and when a cached file came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Metadata {
+    /// Seconds since the Unix epoch, `None` if the file was not fetched.
+    pub fetched: Option<u64>,
+    /// `None` if the file was not fetched.
+    pub source: Option<String>,
+    /// Hex encoded SHA-256 of the contents.
+    pub hash: String,
+}
+
+/// Hex encoded SHA-256 of `contents`.
+pub fn content_hash(contents: &str) -> String {
+    Sha256::digest(contents.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+fn io_error(path: &Path) -> impl FnOnce(std::io::Error) -> AocError + '_ {
+    move |source| AocError::Io {
+        path: Some(path.to_path_buf()),
+        source,
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cache {
+    root: PathBuf,
+}
+
+impl Cache {
+    pub fn new<P: Into<PathBuf>>(root: P) -> Cache {
+        Cache { root: root.into() }
+    }
+
+    /// The cache inside the workspace at `workspace_root`.
+    pub fn in_workspace<P: AsRef<Path>>(workspace_root: P) -> Cache {
+        Cache::new(workspace_root.as_ref().join(CACHE_DIR))
+    }
+
+    pub fn day_dir(&self, year: u32, day: u8) -> PathBuf {
+        self.root
+            .join(year.to_string())
+            .join(format!("day_{:02}", day))
+    }
+
+    pub fn path(&self, year: u32, day: u8, entry: Entry) -> PathBuf {
+        self.day_dir(year, day).join(entry.file_name())
+    }
+
+    pub fn examples_dir(&self, year: u32, day: u8) -> PathBuf {
+        self.day_dir(year, day).join("examples")
+    }
+
+    /// The cached file, if it has been fetched.
+    pub fn get(&self, year: u32, day: u8, entry: Entry) -> Option<PathBuf> {
+        Some(self.path(year, day, entry)).filter(|path| path.exists())
+    }
+
+    /// Stores freshly fetched `contents` and records where they came from.
+    pub fn store(
+        &self,
+        year: u32,
+        day: u8,
+        entry: Entry,
+        contents: &str,
+        source: &str,
+    ) -> Result<PathBuf> {
+        let dir = self.day_dir(year, day);
+        fs::create_dir_all(&dir).map_err(io_error(&dir))?;
+        let path = self.path(year, day, entry);
+        fs::write(&path, contents).map_err(io_error(&path))?;
+
+        let fetched = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        let mut meta = self.read_meta(year, day)?;
+        let mut record = toml::Table::new();
+        record.insert("fetched".to_string(), toml::Value::Integer(fetched as i64));
+        record.insert(
+            "source".to_string(),
+            toml::Value::String(source.to_string()),
+        );
+        record.insert(
+            "hash".to_string(),
+            toml::Value::String(content_hash(contents)),
+        );
+        meta.insert(entry.file_name().to_string(), toml::Value::Table(record));
+        let meta_path = dir.join(META_FILE);
+        fs::write(&meta_path, meta.to_string()).map_err(io_error(&meta_path))?;
+        Ok(path)
+    }
+
+    /// What was recorded when `entry` was stored, if it has been.
+    pub fn metadata(&self, year: u32, day: u8, entry: Entry) -> Result<Option<Metadata>> {
+        let meta = self.read_meta(year, day)?;
+        let record = match meta.get(entry.file_name()).and_then(|v| v.as_table()) {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+        let bad = || {
+            AocError::parse(format!(
+                "{}: bad [{}] entry",
+                self.day_dir(year, day).join(META_FILE).display(),
+                entry.file_name()
+            ))
+        };
+        Ok(Some(Metadata {
+            fetched: match record.get("fetched") {
+                Some(v) => Some(v.as_integer().ok_or_else(bad)? as u64),
+                None => None,
+            },
+            source: match record.get("source") {
+                Some(v) => Some(v.as_str().ok_or_else(bad)?.to_string()),
+                None => None,
+            },
+            hash: record
+                .get("hash")
+                .and_then(|v| v.as_str())
+                .ok_or_else(bad)?
+                .to_string(),
+        }))
+    }
+
+    fn read_meta(&self, year: u32, day: u8) -> Result<toml::Table> {
+        let path = self.day_dir(year, day).join(META_FILE);
+        if !path.exists() {
+            return Ok(toml::Table::new());
+        }
+        read_to_string(&path)?
+            .parse::<toml::Table>()
+            .map_err(|e| AocError::parse(format!("{}: {}", path.display(), e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn stores_contents_with_metadata() {
+        let root = env::temp_dir().join(format!("aoc_common_cache_{}", std::process::id()));
+        let cache = Cache::new(&root);
+        assert_eq!(cache.get(2021, 3, Entry::Input), None);
+
+        let path = cache
+            .store(
+                2021,
+                3,
+                Entry::Input,
+                "00100\n",
+                "https://example.com/2021/day/3/input",
+            )
+            .unwrap();
+        assert_eq!(path, root.join("2021").join("day_03").join("input"));
+        assert_eq!(cache.get(2021, 3, Entry::Input), Some(path));
+        assert_eq!(cache.get(2022, 3, Entry::Input), None);
+
+        let meta = cache.metadata(2021, 3, Entry::Input).unwrap().unwrap();
+        assert_eq!(
+            meta.source.as_deref(),
+            Some("https://example.com/2021/day/3/input")
+        );
+        assert_eq!(meta.hash, content_hash("00100\n"));
+        assert!(meta.fetched.is_some_and(|fetched| fetched > 0));
+        assert_eq!(cache.metadata(2021, 3, Entry::Puzzle).unwrap(), None);
+
+        // An input put in the cache by hand has only its hash.
+        let meta_path = cache.day_dir(2021, 4).join(META_FILE);
+        fs::create_dir_all(cache.day_dir(2021, 4)).unwrap();
+        fs::write(&meta_path, "[input]\nhash = \"ab12\"\n").unwrap();
+        let meta = cache.metadata(2021, 4, Entry::Input).unwrap().unwrap();
+        assert_eq!((meta.fetched, meta.source), (None, None));
+        assert_eq!(meta.hash, "ab12");
+
+        fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,472 @@
+//! Differential testing on random inputs. A day that has a clever solution can also
+//! have a slow, obvious one in [`Solution::reference`], and a way to make up valid
+//! inputs in [`Solution::generate`]. [`run`] feeds generated inputs to both and
+//! compares the answers. When they disagree, the input is shrunk one line or one
+//! number at a time, for as long as the answers still disagree, so the
+//! counterexample ends up small enough to debug by hand.
+//!
+//! The reference decides what counts as valid input: anything it rejects with an
+//! error, or panics on, is skipped. That keeps the shrinking from wandering off into
+//! inputs the puzzle could never have given. A reference that can only work out
+//! part 1 for an input still has that part checked.
+//!
+//! [`Solution::reference`]: crate::Solution::reference
+//! [`Solution::generate`]: crate::Solution::generate
+
+use crate::error::{AocError, Result};
+use crate::solution::{Answer, Generator, Part, Reference, Solver};
+use std::cell::Cell;
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+/// A small, seedable random number generator (SplitMix64). Not for anything that
+/// needs to be unpredictable, only for inputs that can be made again from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from `range`, which must not be empty.
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (low, high) = range.into_inner();
+        assert!(low <= high, "cannot pick from an empty range");
+        let span = high.abs_diff(low).wrapping_add(1);
+        if span == 0 {
+            // The range covers every i64.
+            return self.next_u64() as i64;
+        }
+        low.wrapping_add((self.next_u64() % span) as i64)
+    }
+
+    /// An index below `len`, which must not be zero.
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range(0..=len as i64 - 1) as usize
+    }
+
+    /// True with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1_u64 << 53) as f64) < p
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+/// How many inputs [`run`] tries, and where its random numbers start.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    pub cases: usize,
+    pub seed: u64,
+}
+
+/// An input on which the solution and the reference disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counterexample {
+    /// Which generated input it was shrunk from, counting from 0.
+    pub case: usize,
+    pub input: String,
+    /// The reference's answers. `None` for a part it could not work out.
+    pub expected: [Option<String>; 2],
+    /// What the solution gave: an answer, an error or a panic. `None` for a part
+    /// that is not solved yet.
+    pub found: [Option<String>; 2],
+}
+
+impl fmt::Display for Counterexample {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (expected, found)) in self.expected.iter().zip(&self.found).enumerate() {
+            if let (Some(expected), Some(found)) = (expected, found) {
+                if found != expected {
+                    writeln!(f, "part {}: expected {}, found {}", i + 1, expected, found)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What [`run`] found.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    /// Inputs both solutions were run on.
+    pub checked: usize,
+    /// Generated inputs the reference rejected.
+    pub skipped: usize,
+    pub counterexample: Option<Counterexample>,
+}
+
+thread_local! {
+    /// Whether this thread's panics are expected, so their messages are not shown.
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/// Keeps this thread's panics quiet until dropped.
+struct Quiet {
+    was: bool,
+}
+
+impl Quiet {
+    /// The panic hook is only ever swapped once, for one that passes every panic
+    /// on to the hook it replaced unless its thread is quiet. Other threads, like
+    /// tests running alongside, still report their panics as usual.
+    fn new() -> Quiet {
+        QUIET_HOOK.call_once(|| {
+            let previous = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                if !QUIET.with(Cell::get) {
+                    previous(info);
+                }
+            }));
+        });
+        Quiet {
+            was: QUIET.with(|quiet| quiet.replace(true)),
+        }
+    }
+}
+
+impl Drop for Quiet {
+    fn drop(&mut self) {
+        QUIET.with(|quiet| quiet.set(self.was));
+    }
+}
+
+/// The message a panic was raised with.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "a panic".to_string())
+}
+
+/// What came out for each part, `None` where there is nothing to compare.
+type Outcomes = [Option<String>; 2];
+
+/// The reference's answers, or `None` when it does not accept `input`.
+fn expected(reference: Reference, input: &str) -> Option<Outcomes> {
+    match panic::catch_unwind(|| reference(input)) {
+        Ok(Ok((part1, part2))) => Some([Some(part1.to_string()), part2.map(|a| a.to_string())]),
+        _ => None,
+    }
+}
+
+fn found(solve: Solver, input: &str) -> Outcomes {
+    let describe = |answer: Result<Answer>| match answer {
+        Ok(answer) => Some(answer.to_string()),
+        Err(AocError::Unsolved) => None,
+        Err(e) => Some(format!("error: {}", e)),
+    };
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(input, &Part::BOTH))) {
+        Ok(Ok(answers)) => {
+            let mut answers = answers.into_iter().map(describe);
+            [answers.next().flatten(), answers.next().flatten()]
+        }
+        Ok(Err(e)) => [Some(format!("error: {}", e)), None],
+        Err(payload) => [Some(format!("panicked: {}", panic_message(payload))), None],
+    }
+}
+
+/// Both outcomes for `input`, when the reference accepts it and the solution
+/// disagrees with it.
+fn disagreement(reference: Reference, solve: Solver, input: &str) -> Option<(Outcomes, Outcomes)> {
+    let expected = expected(reference, input)?;
+    let found = found(solve, input);
+    let differs = expected
+        .iter()
+        .zip(&found)
+        .any(|pair| matches!(pair, (Some(expected), Some(found)) if found != expected));
+    differs.then_some((expected, found))
+}
+
+/// Checks `solve` against `reference` on `options.cases` inputs made by `generate`,
+/// stopping at the first disagreement and shrinking it. Fails when the day has no
+/// generator or reference.
+pub fn run(
+    generate: Generator,
+    reference: Reference,
+    solve: Solver,
+    options: &Options,
+) -> Result<Report> {
+    let mut rng = Rng::new(options.seed);
+    // Panics are part of what is being compared, so keep their messages quiet.
+    let _quiet = Quiet::new();
+    let mut report = Report {
+        checked: 0,
+        skipped: 0,
+        counterexample: None,
+    };
+    for case in 0..options.cases {
+        let input = generate(&mut rng)?;
+        match panic::catch_unwind(|| reference(&input)) {
+            Ok(Ok(_)) => {}
+            Ok(Err(e @ AocError::Usage(_))) => return Err(e),
+            _ => {
+                report.skipped += 1;
+                continue;
+            }
+        }
+        report.checked += 1;
+        if disagreement(reference, solve, &input).is_some() {
+            let input = shrink(&input, |input| {
+                disagreement(reference, solve, input).is_some()
+            });
+            let (expected, found) =
+                disagreement(reference, solve, &input).expect("shrinking keeps the disagreement");
+            report.counterexample = Some(Counterexample {
+                case,
+                input,
+                expected,
+                found,
+            });
+            break;
+        }
+    }
+    Ok(report)
+}
+
+/// Shrinks `input` for as long as `fails` holds: first by dropping runs of lines,
+/// longest first, then by making each number in it smaller. Returns the smallest
+/// failing input found, or `input` itself when it does not fail.
+pub fn shrink<F: FnMut(&str) -> bool>(input: &str, mut fails: F) -> String {
+    let mut lines: Vec<String> = input.lines().map(str::to_string).collect();
+    let join = |lines: &[String]| {
+        let mut text = lines.join("\n");
+        text.push('\n');
+        text
+    };
+    if !fails(&join(&lines)) {
+        return input.to_string();
+    }
+    let mut progress = true;
+    while progress {
+        progress = false;
+
+        let mut size = lines.len() / 2;
+        while size > 0 {
+            let mut start = 0;
+            while start + size <= lines.len() {
+                let mut candidate = lines.clone();
+                candidate.drain(start..start + size);
+                if !candidate.is_empty() && fails(&join(&candidate)) {
+                    lines = candidate;
+                    progress = true;
+                } else {
+                    start += 1;
+                }
+            }
+            size /= 2;
+        }
+
+        for i in 0..lines.len() {
+            let mut start = 0;
+            while let Some((from, to)) = next_number(&lines[i], start) {
+                let n: u64 = match lines[i][from..to].parse() {
+                    Ok(n) => n,
+                    Err(_) => {
+                        start = to;
+                        continue;
+                    }
+                };
+                let smaller = [0, 1, n / 2, n.saturating_sub(1)]
+                    .into_iter()
+                    .filter(|&m| m < n)
+                    .find(|m| {
+                        let mut candidate = lines.clone();
+                        candidate[i].replace_range(from..to, &m.to_string());
+                        fails(&join(&candidate))
+                    });
+                match smaller {
+                    Some(m) => {
+                        lines[i].replace_range(from..to, &m.to_string());
+                        progress = true;
+                    }
+                    None => start = to,
+                }
+            }
+        }
+    }
+    join(&lines)
+}
+
+/// The byte range of the first run of digits in `line` at or after `start`.
+fn next_number(line: &str, start: usize) -> Option<(usize, usize)> {
+    let from = start + line[start..].find(|c: char| c.is_ascii_digit())?;
+    let len = line[from..]
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(line.len() - from);
+    Some((from, from + len))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rng_is_repeatable_and_stays_in_range() {
+        let mut a = Rng::new(7);
+        let mut b = Rng::new(7);
+        for _ in 0..1000 {
+            let n = a.range(-3..=3);
+            assert_eq!(n, b.range(-3..=3));
+            assert!((-3..=3).contains(&n));
+        }
+        assert_eq!(Rng::new(1).range(5..=5), 5);
+        let mut items = [1, 2, 3, 4, 5];
+        Rng::new(3).shuffle(&mut items);
+        items.sort_unstable();
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn shrinks_to_the_smallest_failing_input() {
+        // Fails whenever some line holds a number of at least 50.
+        let fails = |input: &str| {
+            input
+                .split(|c: char| !c.is_ascii_digit())
+                .any(|n| n.parse::<u64>().is_ok_and(|n| n >= 50))
+        };
+        let input = "3 4\n10\nx 77 y 400\n2\n";
+        assert_eq!(shrink(input, fails), "x 0 y 50\n");
+        assert_eq!(shrink("1\n2\n", fails), "1\n2\n");
+    }
+
+    fn generate(rng: &mut Rng) -> Result<String> {
+        let numbers: Vec<String> = (0..rng.range(1..=20))
+            .map(|_| rng.range(0..=100).to_string())
+            .collect();
+        Ok(numbers.join("\n"))
+    }
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        input
+            .lines()
+            .map(|line| line.parse().map_err(|_| AocError::parse("not a number")))
+            .collect()
+    }
+
+    fn reference(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let numbers = parse(input)?;
+        let max = numbers.iter().max().copied().unwrap_or(0);
+        Ok((max.into(), Some(numbers.iter().sum::<i64>().into())))
+    }
+
+    /// Only knows the sum when there are a few numbers.
+    fn reference_for_few(input: &str) -> Result<(Answer, Option<Answer>)> {
+        let numbers = parse(input)?;
+        let max = numbers.iter().max().copied().unwrap_or(0);
+        let sum = (numbers.len() <= 3).then(|| numbers.iter().sum::<i64>().into());
+        Ok((max.into(), sum))
+    }
+
+    /// Gets the maximum wrong when it is above 90.
+    fn solve(input: &str, _parts: &[Part]) -> Result<Vec<Result<Answer>>> {
+        let numbers = parse(input)?;
+        let max = numbers.iter().copied().filter(|&n| n <= 90).max();
+        Ok(vec![Ok(max.unwrap_or(0).into()), Err(AocError::Unsolved)])
+    }
+
+    #[test]
+    fn finds_and_shrinks_a_disagreement() {
+        let options = Options {
+            cases: 100,
+            seed: 1,
+        };
+        let report = run(generate, reference, solve, &options).unwrap();
+        let counterexample = report.counterexample.unwrap();
+        assert_eq!(counterexample.input, "91\n");
+        assert_eq!(
+            counterexample.expected,
+            [Some("91".to_string()), Some("91".to_string())]
+        );
+        assert_eq!(counterexample.found, [Some("0".to_string()), None]);
+        assert_eq!(counterexample.to_string(), "part 1: expected 91, found 0\n");
+
+        let agreeing = run(
+            generate,
+            reference,
+            |input, _| {
+                let (part1, part2) = reference(input)?;
+                Ok(vec![Ok(part1), part2.ok_or(AocError::Unsolved)])
+            },
+            &options,
+        )
+        .unwrap();
+        assert_eq!((agreeing.checked, agreeing.skipped), (100, 0));
+        assert!(agreeing.counterexample.is_none());
+    }
+
+    #[test]
+    fn checks_part_1_when_the_reference_cannot_work_out_part_2() {
+        let options = Options {
+            cases: 100,
+            seed: 1,
+        };
+        // Wrong about the sum of anything but a single number.
+        let solve: Solver = |input, _| {
+            let numbers = parse(input)?;
+            let max = numbers.iter().max().copied().unwrap_or(0);
+            Ok(vec![Ok(max.into()), Ok(numbers[0].into())])
+        };
+        let report = run(generate, reference_for_few, solve, &options).unwrap();
+        let counterexample = report.counterexample.unwrap();
+        assert_eq!(counterexample.input.lines().count(), 2);
+        assert_eq!(counterexample.found[0], counterexample.expected[0]);
+
+        let buggy = run(generate, reference_for_few, self::solve, &options).unwrap();
+        assert_eq!(buggy.counterexample.unwrap().input, "91\n");
+    }
+
+    #[test]
+    fn only_quiets_panics_while_checking() {
+        let options = Options { cases: 5, seed: 1 };
+        let report = run(
+            generate,
+            reference,
+            |_, _| panic!("expected by the test"),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(
+            report.counterexample.unwrap().found[0].as_deref(),
+            Some("panicked: expected by the test")
+        );
+        assert!(
+            !QUIET.with(Cell::get),
+            "the thread is loud again afterwards"
+        );
+        let other = std::thread::spawn(|| {
+            let _quiet = Quiet::new();
+            QUIET.with(Cell::get)
+        });
+        assert!(other.join().unwrap());
+        assert!(
+            !QUIET.with(Cell::get),
+            "quieting one thread leaves the others"
+        );
+    }
+}
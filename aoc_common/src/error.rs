@@ -0,0 +1,175 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything that can go wrong between reading a puzzle input and printing an answer.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read. `path` is `None` when reading from stdin.
+    Io {
+        path: Option<PathBuf>,
+        source: io::Error,
+    },
+    /// The input was read but did not look like what the puzzle promised.
+    Parse(String),
+    /// A line of input did not match its expected layout, see [`SyntaxError`].
+    Syntax(Box<SyntaxError>),
+    /// The input parsed but the puzzle has no answer for it.
+    NoSolution(String),
+    /// The part has not been solved yet.
+    Unsolved,
+    /// A command line tool was asked for something that does not exist.
+    Usage(String),
+    /// The work was stopped for going over its time or memory budget, see
+    /// [`budget`](crate::budget). `progress` is how far it got, and
+    /// `peak_memory` the most bytes it had allocated, when they are known.
+    OverBudget {
+        reason: String,
+        progress: Option<String>,
+        peak_memory: Option<usize>,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, AocError>;
+
+/// Where a line of input stopped matching what the parser expected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyntaxError {
+    /// The input file, `None` for stdin or until the runner knows which file it was.
+    pub path: Option<PathBuf>,
+    /// Counted from 1.
+    pub line: usize,
+    /// Counted from 1, in characters.
+    pub column: usize,
+    pub expected: String,
+    /// The text at `column` that did not match, empty at the end of the line.
+    pub found: String,
+    /// The whole offending line.
+    pub text: String,
+}
+
+impl SyntaxError {
+    fn location(&self) -> String {
+        let path = match &self.path {
+            Some(path) => path.display().to_string(),
+            None => "<stdin>".to_string(),
+        };
+        format!("{}:{}:{}", path, self.line, self.column)
+    }
+
+    fn found(&self) -> String {
+        if self.found.is_empty() {
+            "end of line".to_string()
+        } else {
+            format!("`{}`", self.found)
+        }
+    }
+
+    /// The error as a compiler would show it, with the line and a caret under the
+    /// spot that went wrong.
+    pub fn snippet(&self) -> String {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let underline = "^".repeat(self.found.chars().count().max(1));
+        format!(
+            "error: expected {}, found {}\n{} --> {}\n{} |\n{} | {}\n{} | {}{} expected {}\n",
+            self.expected,
+            self.found(),
+            gutter,
+            self.location(),
+            gutter,
+            number,
+            self.text,
+            gutter,
+            " ".repeat(self.column - 1),
+            underline,
+            self.expected
+        )
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: expected {}, found {}",
+            self.location(),
+            self.expected,
+            self.found()
+        )
+    }
+}
+
+impl AocError {
+    pub fn parse<S: Into<String>>(message: S) -> AocError {
+        AocError::Parse(message.into())
+    }
+
+    pub fn usage<S: Into<String>>(message: S) -> AocError {
+        AocError::Usage(message.into())
+    }
+
+    pub fn no_solution<S: Into<String>>(message: S) -> AocError {
+        AocError::NoSolution(message.into())
+    }
+
+    /// Names the file a syntax error was found in. Other errors are left alone.
+    pub fn in_file(self, path: Option<&Path>) -> AocError {
+        match self {
+            AocError::Syntax(mut error) => {
+                error.path = path.map(Path::to_path_buf);
+                AocError::Syntax(error)
+            }
+            e => e,
+        }
+    }
+
+    /// The error as printed for a person: syntax errors get a snippet of the input,
+    /// everything else a single `error:` line.
+    pub fn report(&self) -> String {
+        match self {
+            AocError::Syntax(error) => error.snippet(),
+            e => format!("error: {}\n", e),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io {
+                path: Some(path),
+                source,
+            } => write!(f, "could not read {}: {}", path.display(), source),
+            AocError::Io { path: None, source } => write!(f, "could not read stdin: {}", source),
+            AocError::Parse(message) => write!(f, "could not parse input: {}", message),
+            AocError::Syntax(error) => write!(f, "could not parse input: {}", error),
+            AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+            AocError::Unsolved => write!(f, "not solved yet"),
+            AocError::Usage(message) => write!(f, "{}", message),
+            AocError::OverBudget {
+                reason,
+                progress,
+                peak_memory,
+            } => {
+                write!(f, "stopped early, {}", reason)?;
+                if let Some(progress) = progress {
+                    write!(f, " after getting to {}", progress)?;
+                }
+                if let Some(bytes) = peak_memory {
+                    write!(f, " (peak memory {})", crate::budget::format_bytes(*bytes))?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,391 @@
+//! Integer points and vectors in two and three dimensions. The same type serves as
+//! a position and as the step between two positions.
+//!
+//! In two dimensions `y` grows downwards, as it does in the maps the puzzles draw,
+//! so [`Direction::Up`] is `(0, -1)` and turning right goes clockwise on screen.
+
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types a point can be made of.
+pub trait Coord:
+    Copy
+    + Ord
+    + Hash
+    + Default
+    + fmt::Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    const MIN: Self;
+    const MAX: Self;
+
+    fn abs(self) -> Self;
+
+    fn signum(self) -> Self;
+
+    fn saturating_add(self, other: Self) -> Self;
+
+    fn saturating_sub(self, other: Self) -> Self;
+}
+
+macro_rules! coord {
+    ($($t:ty),*) => {
+        $(
+            impl Coord for $t {
+                const ZERO: $t = 0;
+                const ONE: $t = 1;
+                const MIN: $t = <$t>::MIN;
+                const MAX: $t = <$t>::MAX;
+
+                fn abs(self) -> $t {
+                    <$t>::abs(self)
+                }
+
+                fn signum(self) -> $t {
+                    <$t>::signum(self)
+                }
+
+                fn saturating_add(self, other: $t) -> $t {
+                    <$t>::saturating_add(self, other)
+                }
+
+                fn saturating_sub(self, other: $t) -> $t {
+                    <$t>::saturating_sub(self, other)
+                }
+            }
+        )*
+    };
+}
+
+coord!(i8, i16, i32, i64, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coord> Point2<T> {
+    pub const fn new(x: T, y: T) -> Point2<T> {
+        Point2 { x, y }
+    }
+
+    pub fn origin() -> Point2<T> {
+        Point2::new(T::ZERO, T::ZERO)
+    }
+
+    pub fn scale(self, factor: T) -> Point2<T> {
+        Point2::new(self.x * factor, self.y * factor)
+    }
+
+    pub fn manhattan(self, other: Point2<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// The distance when diagonal steps are allowed, so touching points are 1 apart.
+    pub fn chebyshev(self, other: Point2<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// Each coordinate replaced by its sign, turning a vector into a single step.
+    pub fn signum(self) -> Point2<T> {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    /// Each coordinate clamped to `min..=max`.
+    pub fn clamp(self, min: T, max: T) -> Point2<T> {
+        Point2::new(self.x.clamp(min, max), self.y.clamp(min, max))
+    }
+
+    /// One step, straight or diagonal, from `self` towards `target`.
+    pub fn step_towards(self, target: Point2<T>) -> Point2<T> {
+        self + (target - self).signum()
+    }
+
+    /// Every point from `self` to `end` inclusive, stepping with
+    /// [`step_towards`](Point2::step_towards). For a straight or diagonal line.
+    pub fn line_to(self, end: Point2<T>) -> impl Iterator<Item = Point2<T>> {
+        std::iter::successors(Some(self), move |&p| {
+            (p != end).then(|| p.step_towards(end))
+        })
+    }
+
+    /// A quarter turn clockwise about the origin, as seen on screen.
+    pub fn rotate_right(self) -> Point2<T> {
+        Point2::new(-self.y, self.x)
+    }
+
+    /// A quarter turn anticlockwise about the origin, as seen on screen.
+    pub fn rotate_left(self) -> Point2<T> {
+        Point2::new(self.y, -self.x)
+    }
+
+    /// The four points one straight step away.
+    pub fn neighbours4(self) -> [Point2<T>; 4] {
+        Direction::ALL.map(|direction| self + direction.delta())
+    }
+}
+
+impl<T: Coord> Add for Point2<T> {
+    type Output = Point2<T>;
+
+    fn add(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Point2<T> {
+    type Output = Point2<T>;
+
+    fn sub(self, other: Point2<T>) -> Point2<T> {
+        Point2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> Neg for Point2<T> {
+    type Output = Point2<T>;
+
+    fn neg(self) -> Point2<T> {
+        Point2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point2<T> {
+    fn add_assign(&mut self, other: Point2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> SubAssign for Point2<T> {
+    fn sub_assign(&mut self, other: Point2<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coord> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn origin() -> Point3<T> {
+        Point3::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    pub fn scale(self, factor: T) -> Point3<T> {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    pub fn manhattan(self, other: Point3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(self, other: Point3<T>) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn signum(self) -> Point3<T> {
+        Point3::new(self.x.signum(), self.y.signum(), self.z.signum())
+    }
+
+    pub fn clamp(self, min: T, max: T) -> Point3<T> {
+        Point3::new(
+            self.x.clamp(min, max),
+            self.y.clamp(min, max),
+            self.z.clamp(min, max),
+        )
+    }
+
+    pub fn step_towards(self, target: Point3<T>) -> Point3<T> {
+        self + (target - self).signum()
+    }
+
+    /// A quarter turn about the x axis, taking y towards z.
+    pub fn rotate_x(self) -> Point3<T> {
+        Point3::new(self.x, -self.z, self.y)
+    }
+
+    /// A quarter turn about the y axis, taking z towards x.
+    pub fn rotate_y(self) -> Point3<T> {
+        Point3::new(self.z, self.y, -self.x)
+    }
+
+    /// A quarter turn about the z axis, taking x towards y.
+    pub fn rotate_z(self) -> Point3<T> {
+        Point3::new(-self.y, self.x, self.z)
+    }
+
+    /// The six points one step away along an axis.
+    pub fn neighbours6(self) -> [Point3<T>; 6] {
+        let (one, zero) = (T::ONE, T::ZERO);
+        [
+            Point3::new(one, zero, zero),
+            Point3::new(-one, zero, zero),
+            Point3::new(zero, one, zero),
+            Point3::new(zero, -one, zero),
+            Point3::new(zero, zero, one),
+            Point3::new(zero, zero, -one),
+        ]
+        .map(|step| self + step)
+    }
+}
+
+impl<T: Coord> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coord> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Coord> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T: Coord> AddAssign for Point3<T> {
+    fn add_assign(&mut self, other: Point3<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coord> SubAssign for Point3<T> {
+    fn sub_assign(&mut self, other: Point3<T>) {
+        *self = *self - other;
+    }
+}
+
+/// One of the four straight directions on a map.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U`/`R`/`D`/`L`, `N`/`E`/`S`/`W` or an arrow `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::Up),
+            'R' | 'E' | '>' => Some(Direction::Right),
+            'D' | 'S' | 'v' => Some(Direction::Down),
+            'L' | 'W' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    /// The step one square in this direction.
+    pub fn delta<T: Coord>(self) -> Point2<T> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        match self {
+            Direction::Up => Point2::new(zero, -one),
+            Direction::Right => Point2::new(one, zero),
+            Direction::Down => Point2::new(zero, one),
+            Direction::Left => Point2::new(-one, zero),
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn distances() {
+        let a = Point2::new(1, -2);
+        let b = Point2::new(4, 2);
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(b - a, Point2::new(3, 4));
+        assert_eq!((b - a).scale(2), Point2::new(6, 8));
+        assert_eq!(Point3::new(1, 2, 3).manhattan(Point3::origin()), 6);
+        assert_eq!(Point3::new(1, -5, 3).chebyshev(Point3::origin()), 5);
+    }
+
+    #[test]
+    fn stepping() {
+        let a = Point2::new(0, 0);
+        assert_eq!(a.step_towards(Point2::new(5, -2)), Point2::new(1, -1));
+        assert_eq!(a.step_towards(a), a);
+        assert_eq!(
+            a.line_to(Point2::new(0, 3)).collect::<Vec<_>>(),
+            [(0, 0), (0, 1), (0, 2), (0, 3)].map(|(x, y)| Point2::new(x, y))
+        );
+        assert_eq!(Point2::new(7, -9).clamp(-1, 1), Point2::new(1, -1));
+    }
+
+    #[test]
+    fn turning_and_rotating_agree() {
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.delta::<i32>().rotate_right(),
+                direction.turn_right().delta()
+            );
+            assert_eq!(
+                direction.delta::<i32>().rotate_left(),
+                direction.turn_left().delta()
+            );
+            assert_eq!(direction.reverse().delta::<i32>(), -direction.delta());
+        }
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        let p = Point3::new(1, 2, 3);
+        assert_eq!(p.rotate_x().rotate_x().rotate_x().rotate_x(), p);
+        assert_eq!(Point3::new(1, 0, 0).rotate_z(), Point3::new(0, 1, 0));
+        assert_eq!(Point3::new(0, 0, 1).rotate_y(), Point3::new(1, 0, 0));
+    }
+}
@@ -0,0 +1,340 @@
+//! A rectangular grid of cells, for the puzzles whose input is a map drawn in text.
+//! Positions are `(x, y)` with `x` the column and `y` the row, counted from the top
+//! left corner.
+
+use crate::error::{AocError, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A cell's position, `(x, y)`.
+pub type Pos = (usize, usize);
+
+/// Up, right, down and left, as `(dx, dy)` steps.
+pub const DIRECTIONS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The four directions of [`DIRECTIONS4`] and the four diagonals, clockwise from up.
+pub const DIRECTIONS8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row after row.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which must all be the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(AocError::parse(format!(
+                    "row {} of the grid is {} wide, not {}",
+                    y + 1,
+                    row.len(),
+                    width
+                )));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    /// Parses one cell per character, one row per line.
+    pub fn parse_with<F>(input: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(char) -> Result<T>,
+    {
+        let rows = input
+            .lines()
+            .map(|line| line.chars().map(&mut cell).collect::<Result<Vec<T>>>())
+            .collect::<Result<Vec<Vec<T>>>>()?;
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Pos) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.1 * self.width + pos.0])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.1 * self.width + pos.0])
+        } else {
+            None
+        }
+    }
+
+    /// The position one `(dx, dy)` step away from `pos`, if it is on the grid.
+    pub fn step(&self, (x, y): Pos, (dx, dy): (isize, isize)) -> Option<Pos> {
+        let next = (x.checked_add_signed(dx)?, y.checked_add_signed(dy)?);
+        self.contains(next).then_some(next)
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The positions up, right, down and left of `pos` that are on the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS4
+            .iter()
+            .filter_map(move |&direction| self.step(pos, direction))
+    }
+
+    /// The positions around `pos`, diagonals included, that are on the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        DIRECTIONS8
+            .iter()
+            .filter_map(move |&direction| self.step(pos, direction))
+    }
+
+    /// The cells of row `y`, left to right.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    /// The cells of column `x`, top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(x).step_by(self.width.max(1))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells from `pos` (not included) to the edge of the grid, one
+    /// `direction` step at a time.
+    pub fn ray(&self, pos: Pos, direction: (isize, isize)) -> impl Iterator<Item = (Pos, &T)> {
+        std::iter::successors(self.step(pos, direction), move |&pos| {
+            self.step(pos, direction)
+        })
+        .map(move |pos| (pos, &self[pos]))
+    }
+
+    /// The first position holding `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Every position holding `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The grid mirrored along its top-left to bottom-right diagonal.
+    pub fn transpose(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter turn clockwise.
+    pub fn rotate_clockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .flat_map(|x| {
+                    self.column(x)
+                        .cloned()
+                        .collect::<Vec<T>>()
+                        .into_iter()
+                        .rev()
+                })
+                .collect(),
+        }
+    }
+
+    /// The grid turned a quarter turn anticlockwise.
+    pub fn rotate_anticlockwise(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width: self.height,
+            height: self.width,
+            cells: (0..self.width)
+                .rev()
+                .flat_map(|x| self.column(x).cloned())
+                .collect(),
+        }
+    }
+
+    /// Draws the grid as text, one line per row.
+    pub fn render<F>(&self, mut cell: F) -> String
+    where
+        F: FnMut(&T) -> char,
+    {
+        self.rows()
+            .map(|row| row.iter().map(&mut cell).chain(['\n']).collect::<String>())
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Parses a map drawn in text, one character per cell.
+    pub fn parse(input: &str) -> Result<Grid<char>> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.render(|&c| c))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "ab.\n.c#\n";
+
+    #[test]
+    fn parses_and_renders_back() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((2, 1)), Some(&'#'));
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), MAP);
+        assert!(Grid::parse("ab\nc\n").is_err());
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (0, 1)]
+        );
+        assert_eq!(grid.neighbours8((1, 0)).count(), 5);
+    }
+
+    #[test]
+    fn walks_rows_columns_and_rays() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!(grid.row(1), ['.', 'c', '#']);
+        assert_eq!(grid.column(1).collect::<String>(), "bc");
+        assert_eq!(
+            grid.ray((0, 0), (1, 0))
+                .map(|(_, &c)| c)
+                .collect::<String>(),
+            "b."
+        );
+        assert_eq!(
+            grid.ray((0, 1), (1, -1)).collect::<Vec<_>>(),
+            [((1, 0), &'b')]
+        );
+        assert_eq!(grid.find(&'c'), Some((1, 1)));
+        assert_eq!(grid.find_all(&'.').collect::<Vec<_>>(), [(2, 0), (0, 1)]);
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::parse(MAP).unwrap();
+        assert_eq!(grid.transpose().to_string(), "a.\nbc\n.#\n");
+        assert_eq!(grid.rotate_clockwise().to_string(), ".a\ncb\n#.\n");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), ".#\nbc\na.\n");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+    }
+}
@@ -0,0 +1,251 @@
+//! Pictures of puzzle state saved as image files, for write-ups. An [`Image`] is a
+//! grid of RGB pixels, usually made from a [`Grid`] and a palette, and saves as PNG
+//! or PPM depending on the file's extension.
+//!
+//! The PNG writer keeps its image data uncompressed (deflate's stored blocks), which
+//! makes files bigger than they need be but keeps the encoder a page long.
+
+use crate::error::{AocError, Result};
+use crate::grid::Grid;
+use std::fs;
+use std::path::Path;
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+pub const BLACK: Rgb = [0, 0, 0];
+pub const WHITE: Rgb = [255, 255, 255];
+pub const GREY: Rgb = [96, 96, 96];
+pub const RED: Rgb = [220, 40, 40];
+pub const GREEN: Rgb = [40, 180, 60];
+pub const BLUE: Rgb = [40, 80, 220];
+pub const YELLOW: Rgb = [230, 200, 60];
+pub const CYAN: Rgb = [60, 200, 220];
+
+/// A colour from dark blue through green to yellow as `value` goes from 0 to `max`,
+/// for heatmaps.
+pub fn gradient(value: f64, max: f64) -> Rgb {
+    const STOPS: [Rgb; 3] = [[30, 20, 90], [40, 170, 90], [250, 230, 40]];
+    let t = if max > 0.0 {
+        (value / max).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let (from, to, t) = if t < 0.5 {
+        (STOPS[0], STOPS[1], t * 2.0)
+    } else {
+        (STOPS[1], STOPS[2], t * 2.0 - 1.0)
+    };
+    [0, 1, 2].map(|i| (from[i] as f64 + (to[i] as f64 - from[i] as f64) * t).round() as u8)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// Row after row.
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, fill: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![fill; width * height],
+        }
+    }
+
+    /// One pixel per cell of `grid`, coloured by `palette`.
+    pub fn from_grid<T, F>(grid: &Grid<T>, mut palette: F) -> Image
+    where
+        F: FnMut(&T) -> Rgb,
+    {
+        Image {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(|(_, cell)| palette(cell)).collect(),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<Rgb> {
+        (x < self.width && y < self.height).then(|| self.pixels[y * self.width + x])
+    }
+
+    /// Colours the pixel at `(x, y)`, ignoring positions outside the image.
+    pub fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    /// Every pixel blown up into a `factor` by `factor` square, so small grids are
+    /// big enough to see.
+    pub fn scale(&self, factor: usize) -> Image {
+        let factor = factor.max(1);
+        let (width, height) = (self.width * factor, self.height * factor);
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Image {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    /// The image as a binary PPM (P6) file.
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        bytes
+    }
+
+    /// The image as an 8-bit RGB PNG file.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut raw = Vec::with_capacity((self.width * 3 + 1) * self.height);
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            // Each row starts with its filter type, 0 for none.
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+
+        let mut ihdr = Vec::with_capacity(13);
+        ihdr.extend((self.width as u32).to_be_bytes());
+        ihdr.extend((self.height as u32).to_be_bytes());
+        // Bit depth 8, colour type 2 (RGB), default compression, filtering and no
+        // interlacing.
+        ihdr.extend([8, 2, 0, 0, 0]);
+
+        let mut png = vec![0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'];
+        chunk(&mut png, b"IHDR", &ihdr);
+        chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// Writes the image to `path` as PNG or PPM, picked by its extension.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let path = path.as_ref();
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => self.to_png(),
+            Some("ppm") => self.to_ppm(),
+            _ => {
+                return Err(AocError::usage(format!(
+                    "cannot tell the image format of {}, use .png or .ppm",
+                    path.display()
+                )))
+            }
+        };
+        fs::write(path, bytes).map_err(|source| AocError::Io {
+            path: Some(path.to_path_buf()),
+            source,
+        })
+    }
+}
+
+/// Appends one PNG chunk: length, type, data and the CRC of type and data.
+fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    let crc = crc32(kind.iter().chain(data));
+    png.extend(crc.to_be_bytes());
+}
+
+/// `data` wrapped as a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65535;
+    let mut out = vec![0x78, 0x01];
+    let blocks: Vec<&[u8]> = if data.is_empty() {
+        vec![&[]]
+    } else {
+        data.chunks(MAX_BLOCK).collect()
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        out.push(u8::from(i == blocks.len() - 1));
+        let len = block.len() as u16;
+        out.extend(len.to_le_bytes());
+        out.extend((!len).to_le_bytes());
+        out.extend(*block);
+    }
+    out.extend(adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32<'a, I: IntoIterator<Item = &'a u8>>(bytes: I) -> u32 {
+    let mut crc = 0xffff_ffff_u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1_u32, 0_u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksums_match_their_references() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn writes_png_and_ppm() {
+        let grid = Grid::from_rows(vec![vec![0, 1], vec![2, 3]]).unwrap();
+        let image = Image::from_grid(&grid, |&v| [v * 10, 0, 255]).scale(2);
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(image.get(3, 0), Some([10, 0, 255]));
+
+        let ppm = image.to_ppm();
+        assert!(ppm.starts_with(b"P6\n4 4\n255\n"));
+        assert_eq!(ppm.len(), 11 + 4 * 4 * 3);
+
+        let png = image.to_png();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 4, 0, 0, 0, 4]);
+        // The image data comes back out of the stored blocks unchanged.
+        let idat = &png[33 + 8..];
+        let block_len = u16::from_le_bytes([idat[3], idat[4]]) as usize;
+        assert_eq!(block_len, 4 * (1 + 4 * 3));
+        assert_eq!(
+            &idat[7..7 + 13],
+            [0, 0, 0, 255, 0, 0, 255, 10, 0, 255, 10, 0, 255]
+        );
+        assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+    }
+
+    #[test]
+    fn gradient_runs_from_dark_to_bright() {
+        assert_eq!(gradient(0.0, 10.0), [30, 20, 90]);
+        assert_eq!(gradient(10.0, 10.0), [250, 230, 40]);
+        assert_eq!(gradient(3.0, 0.0), gradient(0.0, 1.0));
+    }
+}
@@ -0,0 +1,74 @@
+use crate::error::{AocError, Result};
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// File read when no input is given on the command line.
+pub const DEFAULT_INPUT: &str = "input";
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Picks the source from the first command line argument: a path, `-` for stdin,
+    /// or the `input` file in the current directory when nothing is given.
+    pub fn from_args() -> InputSource {
+        InputSource::from_arg(env::args().nth(1))
+    }
+
+    pub fn from_arg(arg: Option<String>) -> InputSource {
+        match arg.as_deref() {
+            Some("-") => InputSource::Stdin,
+            Some(path) => InputSource::Path(PathBuf::from(path)),
+            None => InputSource::Path(PathBuf::from(DEFAULT_INPUT)),
+        }
+    }
+
+    /// The file read, `None` for stdin.
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            InputSource::Path(path) => Some(path),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn read_to_string(&self) -> Result<String> {
+        match self {
+            InputSource::Path(path) => read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| AocError::Io { path: None, source })?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+pub fn read_to_string<P>(filename: P) -> Result<String>
+where
+    P: AsRef<Path>,
+{
+    fs::read_to_string(&filename).map_err(|source| AocError::Io {
+        path: Some(filename.as_ref().to_path_buf()),
+        source,
+    })
+}
+
+/// Reads a whole file into its lines, reporting which file failed instead of
+/// silently dropping unreadable lines.
+pub fn read_lines<P>(filename: P) -> Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    Ok(read_to_string(filename)?
+        .lines()
+        .map(|line| line.to_string())
+        .collect())
+}
@@ -0,0 +1,221 @@
+//! Sets of integers kept as inclusive ranges, for puzzles that cover stretches of a
+//! line far too long to store one number at a time.
+
+use crate::geometry::Coord;
+use std::ops::RangeInclusive;
+
+/// A set of integers held as sorted, disjoint ranges. Ranges that overlap or touch
+/// are merged, so two sets with the same members always compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// `(start, end)`, both included, sorted and with gaps between them.
+    ranges: Vec<(T, T)>,
+}
+
+impl<T: Coord> Default for IntervalSet<T> {
+    fn default() -> IntervalSet<T> {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Coord> IntervalSet<T> {
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    /// Adds every number in `range`.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        // Saturating, as nothing comes after `T::MAX` to touch it.
+        let first = self
+            .ranges
+            .partition_point(|&(_, e)| e.saturating_add(T::ONE) < start);
+        let mut last = first;
+        while last < self.ranges.len() && self.ranges[last].0 <= end.saturating_add(T::ONE) {
+            start = start.min(self.ranges[last].0);
+            end = end.max(self.ranges[last].1);
+            last += 1;
+        }
+        self.ranges.splice(first..last, [(start, end)]);
+    }
+
+    /// Takes out every number in `range`.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|&(_, e)| e < start);
+        let last = self.ranges.partition_point(|&(s, _)| s <= end);
+        if first >= last {
+            return;
+        }
+        let mut kept = Vec::new();
+        if self.ranges[first].0 < start {
+            kept.push((self.ranges[first].0, start - T::ONE));
+        }
+        if self.ranges[last - 1].1 > end {
+            kept.push((end + T::ONE, self.ranges[last - 1].1));
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let i = self.ranges.partition_point(|&(_, e)| e < value);
+        self.ranges.get(i).is_some_and(|&(s, _)| s <= value)
+    }
+
+    /// Whether every number in `self` is also in `other`.
+    pub fn is_subset(&self, other: &IntervalSet<T>) -> bool {
+        self.intersection(other) == *self
+    }
+
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (self.ranges[i], other.ranges[j]);
+            let (start, end) = (a.0.max(b.0), a.1.min(b.1));
+            if start <= end {
+                ranges.push((start, end));
+            }
+            if a.1 < b.1 {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range);
+        }
+        union
+    }
+
+    /// The numbers in `bounds` that are not in the set.
+    pub fn complement(&self, bounds: RangeInclusive<T>) -> IntervalSet<T> {
+        let mut complement = IntervalSet::from(bounds);
+        for range in self.ranges() {
+            complement.remove(range);
+        }
+        complement
+    }
+
+    /// How many numbers are in the set, or `T::MAX` if there are more than that.
+    pub fn len(&self) -> T {
+        self.ranges.iter().fold(T::ZERO, |total, &(s, e)| {
+            total.saturating_add(e.saturating_sub(s).saturating_add(T::ONE))
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The smallest and largest numbers in the set.
+    pub fn bounds(&self) -> Option<RangeInclusive<T>> {
+        Some(self.ranges.first()?.0..=self.ranges.last()?.1)
+    }
+
+    /// The ranges making up the set, in order.
+    pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges.iter().map(|&(s, e)| s..=e)
+    }
+
+    /// The missing stretches between the smallest and largest numbers, in order.
+    pub fn gaps(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.ranges
+            .windows(2)
+            .map(|w| (w[0].1 + T::ONE)..=(w[1].0 - T::ONE))
+    }
+}
+
+impl<T: Coord> From<RangeInclusive<T>> for IntervalSet<T> {
+    fn from(range: RangeInclusive<T>) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl<T: Coord> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> IntervalSet<T> {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i64>) -> Vec<RangeInclusive<i64>> {
+        set.ranges().collect()
+    }
+
+    #[test]
+    fn inserting_merges_overlapping_and_touching_ranges() {
+        let mut set: IntervalSet<i64> = [1..=3, 7..=9, 20..=25].into_iter().collect();
+        assert_eq!(ranges(&set), [1..=3, 7..=9, 20..=25]);
+        set.insert(4..=5);
+        assert_eq!(ranges(&set), [1..=5, 7..=9, 20..=25]);
+        set.insert(6..=21);
+        assert_eq!(ranges(&set), [1..=25]);
+        set.insert(RangeInclusive::new(3, 2));
+        assert_eq!(set.len(), 25);
+        assert!(set.contains(1) && set.contains(25) && !set.contains(26));
+    }
+
+    #[test]
+    fn removing_splits_ranges() {
+        let mut set = IntervalSet::from(0..=10_i64);
+        set.remove(3..=4);
+        set.remove(10..=12);
+        set.remove(-5..=0);
+        assert_eq!(ranges(&set), [1..=2, 5..=9]);
+        assert_eq!(set.gaps().collect::<Vec<_>>(), [3..=4]);
+        assert_eq!(set.bounds(), Some(1..=9));
+        set.remove(0..=20);
+        assert!(set.is_empty());
+        assert_eq!(set.bounds(), None);
+    }
+
+    #[test]
+    fn handles_ranges_up_to_the_largest_number() {
+        let mut set = IntervalSet::from(i64::MAX - 1..=i64::MAX);
+        assert_eq!(ranges(&set), [i64::MAX - 1..=i64::MAX]);
+        assert_eq!(set.len(), 2);
+        set.insert(i64::MAX - 5..=i64::MAX - 3);
+        set.insert(i64::MAX..=i64::MAX);
+        assert_eq!(
+            ranges(&set),
+            [i64::MAX - 5..=i64::MAX - 3, i64::MAX - 1..=i64::MAX]
+        );
+        set.insert(i64::MAX - 2..=i64::MAX - 2);
+        assert_eq!(ranges(&set), [i64::MAX - 5..=i64::MAX]);
+        set.insert(i64::MIN..=0);
+        assert_eq!(set.len(), i64::MAX);
+        assert!(set.contains(i64::MIN) && set.contains(i64::MAX));
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: IntervalSet<i64> = [0..=5, 10..=15].into_iter().collect();
+        let b: IntervalSet<i64> = [3..=12].into_iter().collect();
+        assert_eq!(ranges(&a.intersection(&b)), [3..=5, 10..=12]);
+        assert_eq!(ranges(&a.union(&b)), [0..=15]);
+        assert_eq!(ranges(&a.complement(-2..=20)), [-2..=-1, 6..=9, 16..=20]);
+        assert!(IntervalSet::from(11..=12).is_subset(&a));
+        assert!(!b.is_subset(&a));
+    }
+}
@@ -0,0 +1,35 @@
+//! Shared plumbing for the daily solutions: reading and parsing inputs, reporting
+//! errors, logging diagnostics, time and memory budgets, the `Solution` trait every
+//! day implements, records of the results for other tools, checking recorded
+//! answers, the cache of downloaded inputs, a thread pool for independent work,
+//! integer interval sets, a grid, integer geometry and graph searches for the
+//! puzzles drawn as maps, terminal animations and image files of the simulations,
+//! and differential testing against reference solutions on random inputs.
+
+pub mod animate;
+pub mod budget;
+pub mod cache;
+pub mod differential;
+pub mod error;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod interval;
+pub mod log;
+pub mod parallel;
+pub mod parse;
+pub mod record;
+pub mod regression;
+pub mod search;
+pub mod solution;
+
+pub use error::{AocError, Result, SyntaxError};
+pub use grid::Grid;
+pub use input::{read_lines, InputSource};
+pub use interval::IntervalSet;
+pub use solution::{
+    animate_input, crate_dir, crate_name, image_input, main, solve, solve_parts, solve_timed,
+    time_parts, Animator, Answer, Day, Generator, Painter, Part, Reference, Solution, Solver,
+    TimedSolver, Timer, Timings,
+};
@@ -0,0 +1,169 @@
+//! Diagnostics for the solutions, kept off stdout so it only ever holds answers.
+//! Messages go to stderr, or to a file with [`log_to_file`], when their level is
+//! at least as important as the one set with [`set_level`]. Use the [`error!`],
+//! [`warn!`], [`info!`], [`debug!`] and [`trace!`] macros, which skip formatting
+//! entirely when their level is off, so tracing inside hot loops stays cheap.
+//!
+//! [`error!`]: crate::error!
+//! [`warn!`]: crate::warn!
+//! [`info!`]: crate::info!
+//! [`debug!`]: crate::debug!
+//! [`trace!`]: crate::trace!
+
+use crate::error::{AocError, Result};
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::Mutex;
+
+/// Environment variable holding the level for the day binaries, e.g. `AOC_LOG=debug`.
+pub const LEVEL_VAR: &str = "AOC_LOG";
+
+/// How important a message is, from most to least.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level for `-q` and repeated `-v` flags: warnings by default, errors
+    /// only when quiet, and one more level per `-v`.
+    pub fn from_flags(verbose: u8, quiet: bool) -> Level {
+        if quiet {
+            return Level::Error;
+        }
+        match verbose {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Level> {
+        match name.to_ascii_lowercase().as_str() {
+            "error" => Some(Level::Error),
+            "warn" => Some(Level::Warn),
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.pad(name)
+    }
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+/// Where messages go, stderr when `None`.
+static SINK: Mutex<Option<File>> = Mutex::new(None);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    level as u8 <= LEVEL.load(Ordering::Relaxed)
+}
+
+/// Sets the level from [`LEVEL_VAR`], leaving it alone when unset or unknown.
+pub fn init_from_env() {
+    if let Some(level) = env::var(LEVEL_VAR)
+        .ok()
+        .as_deref()
+        .and_then(Level::from_name)
+    {
+        set_level(level);
+    }
+}
+
+/// Sends every later message to `path` instead of stderr, replacing its contents.
+pub fn log_to_file<P: AsRef<Path>>(path: P) -> Result<()> {
+    let file = File::create(&path).map_err(|source| AocError::Io {
+        path: Some(path.as_ref().to_path_buf()),
+        source,
+    })?;
+    *SINK.lock().unwrap_or_else(|e| e.into_inner()) = Some(file);
+    Ok(())
+}
+
+/// Writes one message. The macros check [`enabled`] before getting here.
+pub fn write(level: Level, target: &str, message: fmt::Arguments) {
+    let line = format!("{:>5} {}: {}\n", level, target, message);
+    let mut sink = SINK.lock().unwrap_or_else(|e| e.into_inner());
+    // A diagnostic that cannot be written is not worth failing a solution over.
+    let _ = match sink.as_mut() {
+        Some(file) => file.write_all(line.as_bytes()),
+        None => io::stderr().write_all(line.as_bytes()),
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn levels_from_flags_and_names() {
+        assert_eq!(Level::from_flags(0, false), Level::Warn);
+        assert_eq!(Level::from_flags(2, false), Level::Debug);
+        assert_eq!(Level::from_flags(7, false), Level::Trace);
+        assert_eq!(Level::from_flags(3, true), Level::Error);
+        assert_eq!(Level::from_name("Debug"), Some(Level::Debug));
+        assert_eq!(Level::from_name("loud"), None);
+        assert!(Level::Error < Level::Trace);
+        assert_eq!(format!("{:>5}|", Level::Warn), " WARN|");
+    }
+}
@@ -0,0 +1,270 @@
+//! Spreading independent pieces of work over threads. A [`Pool`] runs a closure
+//! over every item of a slice, or over a range until it finds something, on worker
+//! threads that live as long as the pool and take the next piece of work whenever
+//! they are free. The calling thread works alongside them.
+//!
+//! Results never depend on the number of threads: [`Pool::map`] keeps the input
+//! order and [`Pool::find_first`] returns the match with the lowest index, the one a
+//! plain loop would have found. The thread count is set once for the whole program
+//! with [`set_threads`], the runner's `--threads` or [`THREADS_VAR`].
+
+use std::env;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicI64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Environment variable holding the thread count for the day binaries.
+pub const THREADS_VAR: &str = "AOC_THREADS";
+
+/// The configured thread count, 0 for one per CPU.
+static THREADS: AtomicUsize = AtomicUsize::new(0);
+
+/// The pool handed out by [`Pool::current`], made again if the thread count changes.
+static CURRENT: Mutex<Option<Arc<Pool>>> = Mutex::new(None);
+
+/// Sets how many threads [`Pool::current`] uses, 0 for one per CPU.
+pub fn set_threads(threads: usize) {
+    THREADS.store(threads, Ordering::Relaxed);
+}
+
+/// The number of threads [`Pool::current`] uses.
+pub fn threads() -> usize {
+    match THREADS.load(Ordering::Relaxed) {
+        0 => thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    }
+}
+
+/// Sets the thread count from [`THREADS_VAR`], leaving it alone when unset or not a
+/// number.
+pub fn init_from_env() {
+    if let Some(threads) = env::var(THREADS_VAR).ok().and_then(|n| n.parse().ok()) {
+        set_threads(threads);
+    }
+}
+
+type Job = Box<dyn FnOnce() + Send>;
+
+/// Worker threads to spread work over, kept until the pool is dropped. Work handed
+/// to a pool must not use the same pool, as its workers would end up waiting on
+/// each other.
+pub struct Pool {
+    threads: usize,
+    jobs: Option<Sender<Job>>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl Pool {
+    /// A pool of `threads` threads, at least one: the calling thread and
+    /// `threads - 1` workers.
+    pub fn new(threads: usize) -> Pool {
+        let threads = threads.max(1);
+        let (jobs, queue) = mpsc::channel::<Job>();
+        let queue = Arc::new(Mutex::new(queue));
+        let workers = (1..threads)
+            .map(|_| {
+                let queue = Arc::clone(&queue);
+                thread::spawn(move || loop {
+                    let job = queue.lock().unwrap_or_else(|e| e.into_inner()).recv();
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                })
+            })
+            .collect();
+        Pool {
+            threads,
+            jobs: Some(jobs),
+            workers,
+        }
+    }
+
+    /// The pool of the configured size, see [`set_threads`].
+    pub fn current() -> Arc<Pool> {
+        let threads = threads();
+        let mut current = CURRENT.lock().unwrap_or_else(|e| e.into_inner());
+        match &*current {
+            Some(pool) if pool.threads == threads => Arc::clone(pool),
+            _ => {
+                let pool = Arc::new(Pool::new(threads));
+                *current = Some(Arc::clone(&pool));
+                pool
+            }
+        }
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
+    /// Runs `task` on `helpers` of the workers and on the calling thread at once,
+    /// returning when every one of them is done. A panic in any of them is raised
+    /// again here once they have all finished.
+    fn broadcast(&self, helpers: usize, task: Arc<dyn Fn() + Send + Sync>) {
+        let (done, finished) = mpsc::channel();
+        let jobs = self
+            .jobs
+            .as_ref()
+            .expect("the pool has its jobs until dropped");
+        for _ in 0..helpers.min(self.workers.len()) {
+            let done = done.clone();
+            let task = Arc::clone(&task);
+            let job: Job = Box::new(move || {
+                let _ = done.send(panic::catch_unwind(AssertUnwindSafe(|| task())).err());
+            });
+            jobs.send(job)
+                .expect("the workers live as long as the pool");
+        }
+        drop(done);
+        let mut panicked = panic::catch_unwind(AssertUnwindSafe(|| task())).err();
+        // Ends once every job, and so its sender, is gone.
+        for payload in finished.iter().flatten() {
+            panicked.get_or_insert(payload);
+        }
+        if let Some(payload) = panicked {
+            panic::resume_unwind(payload);
+        }
+    }
+
+    /// `f` applied to every item, in the order of `items`. The workers outlive the
+    /// call, so the items and `f` are handed over rather than borrowed.
+    pub fn map<T, R, F>(&self, items: Vec<T>, f: F) -> Vec<R>
+    where
+        T: Send + Sync + 'static,
+        R: Send + 'static,
+        F: Fn(&T) -> R + Send + Sync + 'static,
+    {
+        let helpers = (self.threads - 1).min(items.len().saturating_sub(1));
+        if helpers == 0 {
+            return items.iter().map(f).collect();
+        }
+        let results = Arc::new(Mutex::new(Vec::with_capacity(items.len())));
+        let task = {
+            let results = Arc::clone(&results);
+            let next = AtomicUsize::new(0);
+            move || loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(i) else { break };
+                let result = f(item);
+                results
+                    .lock()
+                    .unwrap_or_else(|e| e.into_inner())
+                    .push((i, result));
+            }
+        };
+        self.broadcast(helpers, Arc::new(task));
+        let mut results = mem::take(&mut *results.lock().unwrap_or_else(|e| e.into_inner()));
+        results.sort_unstable_by_key(|&(i, _)| i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// The first `f(i)` that is `Some` for `i` going up through `range`, like
+    /// `range.find_map(f)`. Workers take the range in chunks of `chunk` and stop
+    /// once every index before a match has been tried.
+    pub fn find_first<R, F>(&self, range: RangeInclusive<i64>, chunk: usize, f: F) -> Option<R>
+    where
+        R: Send + 'static,
+        F: Fn(i64) -> Option<R> + Send + Sync + 'static,
+    {
+        if self.threads == 1 {
+            return range.into_iter().find_map(f);
+        }
+        let (start, end) = range.into_inner();
+        let chunk = chunk.max(1) as i64;
+        let found = Arc::new(Mutex::new(None));
+        let task = {
+            let found = Arc::clone(&found);
+            let next = AtomicI64::new(start);
+            // The lowest index with a match so far, so later chunks can be skipped.
+            let found_at = AtomicI64::new(i64::MAX);
+            move || loop {
+                let from = next.fetch_add(chunk, Ordering::Relaxed);
+                if from > end || from > found_at.load(Ordering::Relaxed) {
+                    break;
+                }
+                let to = end.min(from.saturating_add(chunk - 1));
+                if let Some((i, result)) = (from..=to).find_map(|i| f(i).map(|r| (i, r))) {
+                    let mut found = found.lock().unwrap_or_else(|e| e.into_inner());
+                    if found.as_ref().is_none_or(|&(j, _)| i < j) {
+                        found_at.fetch_min(i, Ordering::Relaxed);
+                        *found = Some((i, result));
+                    }
+                }
+            }
+        };
+        self.broadcast(self.threads - 1, Arc::new(task));
+        let found = found.lock().unwrap_or_else(|e| e.into_inner()).take();
+        found.map(|(_, result)| result)
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        // Closing the queue lets each worker finish its loop.
+        drop(self.jobs.take());
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_keeps_the_order_whatever_the_threads() {
+        let items: Vec<u64> = (0..100).collect();
+        let expected: Vec<u64> = items.iter().map(|n| n * n).collect();
+        for threads in [1, 2, 7, 200] {
+            assert_eq!(Pool::new(threads).map(items.clone(), |n| n * n), expected);
+        }
+        assert!(Pool::new(4).map(Vec::<u8>::new(), |&n| n).is_empty());
+    }
+
+    #[test]
+    fn find_first_finds_the_lowest_match() {
+        let f = |i: i64| (i % 37 == 36 || i == 900).then_some(i * 2);
+        for threads in [1, 3, 16] {
+            let pool = Pool::new(threads);
+            assert_eq!(pool.find_first(0..=1000, 5, f), Some(72));
+            assert_eq!(
+                pool.find_first(800..=1000, 1, |i| (i == 900).then_some(i)),
+                Some(900)
+            );
+            assert_eq!(pool.find_first(0..=10, 4, |_| None::<()>), None);
+        }
+    }
+
+    #[test]
+    fn workers_outlive_each_call() {
+        let pool = Pool::new(3);
+        let items: Vec<u64> = (0..20).collect();
+        let mut seen = vec![];
+        for _ in 0..5 {
+            seen.extend(pool.map(items.clone(), |_| {
+                thread::sleep(std::time::Duration::from_millis(1));
+                thread::current().id()
+            }));
+        }
+        seen.sort_by_key(|id| format!("{:?}", id));
+        seen.dedup();
+        assert!(seen.len() <= 3, "{} threads for a pool of 3", seen.len());
+    }
+
+    #[test]
+    fn panics_reach_the_caller_and_the_pool_carries_on() {
+        let pool = Pool::new(4);
+        let items: Vec<u64> = (0..10).collect();
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            pool.map(items.clone(), |&n| if n == 7 { panic!("seven") } else { n })
+        }));
+        assert!(result.is_err());
+        assert_eq!(pool.map(items, |n| n + 1)[9], 10);
+    }
+}
@@ -0,0 +1,198 @@
+//! Reading puzzle input one line at a time with a [`Scanner`] that knows where it is,
+//! so a line that does not match fails with a [`SyntaxError`] naming the line, the
+//! column and what was expected there, instead of a panic from an `unwrap`.
+
+use crate::error::{AocError, Result, SyntaxError};
+use std::str::FromStr;
+
+/// Every line of `input`, numbered from 1 and ready to scan.
+pub fn lines(input: &str) -> impl Iterator<Item = Scanner<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, text)| Scanner::new(text, i + 1))
+}
+
+/// A position within one line of input, moving forward as pieces are read off it.
+#[derive(Debug, Clone)]
+pub struct Scanner<'a> {
+    text: &'a str,
+    line: usize,
+    /// Byte offset of the next unread character.
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    /// Starts at the beginning of `text`, which is line `line` of the input.
+    pub fn new(text: &'a str, line: usize) -> Scanner<'a> {
+        Scanner { text, line, pos: 0 }
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// The column of the next unread character, counted from 1.
+    pub fn column(&self) -> usize {
+        self.text[..self.pos].chars().count() + 1
+    }
+
+    /// Everything not read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    pub fn at_end(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    /// A syntax error at the current position, saying what should have been here.
+    pub fn error<S: Into<String>>(&self, expected: S) -> AocError {
+        let found = self.rest().split_whitespace().next().unwrap_or("");
+        AocError::Syntax(Box::new(SyntaxError {
+            path: None,
+            line: self.line,
+            column: self.column(),
+            expected: expected.into(),
+            found: found.to_string(),
+            text: self.text.to_string(),
+        }))
+    }
+
+    /// Reads exactly `literal`.
+    pub fn literal(&mut self, literal: &str) -> Result<()> {
+        if self.rest().starts_with(literal) {
+            self.pos += literal.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("`{}`", literal.trim())))
+        }
+    }
+
+    /// Reads whichever of `literals` comes next, trying them in order.
+    pub fn one_of<'l>(&mut self, literals: &[&'l str]) -> Result<&'l str> {
+        for &literal in literals {
+            if self.rest().starts_with(literal) {
+                self.pos += literal.len();
+                return Ok(literal);
+            }
+        }
+        let names: Vec<String> = literals
+            .iter()
+            .map(|literal| format!("`{}`", literal.trim()))
+            .collect();
+        Err(self.error(format!("one of {}", names.join(", "))))
+    }
+
+    /// Reads an integer, with a leading `-` if it is negative.
+    pub fn number<T: FromStr>(&mut self) -> Result<T> {
+        let rest = self.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+        let token = &rest[..sign + digits];
+        match token.parse() {
+            Ok(n) if digits > 0 => {
+                self.pos += token.len();
+                Ok(n)
+            }
+            _ => Err(self.error("a number")),
+        }
+    }
+
+    /// Reads a run of letters and digits.
+    pub fn word(&mut self) -> Result<&'a str> {
+        let rest = self.rest();
+        let len = rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len());
+        if len == 0 {
+            return Err(self.error("a word"));
+        }
+        self.pos += len;
+        Ok(&rest[..len])
+    }
+
+    /// Reads one or more items with `item`, separated by `separator`.
+    pub fn list<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>>
+    where
+        F: FnMut(&mut Scanner<'a>) -> Result<T>,
+    {
+        let mut items = vec![item(self)?];
+        while self.rest().starts_with(separator) {
+            self.pos += separator.len();
+            items.push(item(self)?);
+        }
+        Ok(items)
+    }
+
+    /// Checks that the whole line has been read.
+    pub fn end(&self) -> Result<()> {
+        if self.at_end() {
+            Ok(())
+        } else {
+            Err(self.error("end of line"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn syntax(error: AocError) -> SyntaxError {
+        match error {
+            AocError::Syntax(error) => *error,
+            e => panic!("expected a syntax error, got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn reads_a_line_piece_by_piece() {
+        let mut line = Scanner::new("Valve AA has flow rate=-13; leads to CC, DD", 1);
+        line.literal("Valve ").unwrap();
+        assert_eq!(line.word().unwrap(), "AA");
+        line.literal(" has flow rate=").unwrap();
+        assert_eq!(line.number::<i32>().unwrap(), -13);
+        assert_eq!(
+            line.one_of(&["; lead to ", "; leads to "]).unwrap(),
+            "; leads to "
+        );
+        assert_eq!(line.list(", ", |s| s.word()).unwrap(), ["CC", "DD"]);
+        assert!(line.end().is_ok());
+    }
+
+    #[test]
+    fn errors_point_at_the_problem() {
+        let mut scanners = lines("x=1\nx=12a, y=z");
+        let mut first = scanners.next().unwrap();
+        first.literal("x=").unwrap();
+        first.number::<u8>().unwrap();
+        assert!(first.end().is_ok());
+
+        let mut second = scanners.next().unwrap();
+        second.literal("x=").unwrap();
+        second.number::<u8>().unwrap();
+        let error = syntax(second.end().unwrap_err());
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.found, "a,");
+        assert_eq!(
+            error.to_string(),
+            "<stdin>:2:5: expected end of line, found `a,`"
+        );
+        assert_eq!(
+            error.snippet(),
+            "error: expected end of line, found `a,`\n  --> <stdin>:2:5\n  |\n2 | x=12a, y=z\n  |     ^^ expected end of line\n"
+        );
+
+        let mut third = Scanner::new("y=300", 3);
+        third.literal("y=").unwrap();
+        let error = syntax(third.number::<u8>().unwrap_err());
+        assert_eq!((error.column, error.expected.as_str()), (3, "a number"));
+        let error = syntax(Scanner::new("", 4).literal("Valve ").unwrap_err());
+        assert!(error
+            .to_string()
+            .ends_with("expected `Valve`, found end of line"));
+    }
+}
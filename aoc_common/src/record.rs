@@ -0,0 +1,156 @@
+//! One result per day and part in a shape other tools can read: the answer and its
+//! type, how long the part took and a hash of the input, so results from different
+//! machines can be told apart when the inputs differ. [`Record::to_json`] writes
+//! one as a line of JSON:
+//!
+//! ```text
+//! {"day":1,"part":1,"status":"solved","answer":24000,"type":"number","duration_us":12,"input_hash":"9fb1…"}
+//! ```
+//!
+//! `status` is `solved`, `unsolved` or `error`. Only solved parts have an answer
+//! and a type, and only failed ones an `error` message.
+
+use crate::cache::content_hash;
+use crate::solution::{Answer, Day, Part};
+use crate::AocError;
+use serde::Serialize;
+use std::time::Duration;
+
+/// How one part turned out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    Unsolved,
+    Error(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: Part,
+    pub outcome: Outcome,
+    /// Time spent on the part itself, not counting parsing.
+    pub duration: Duration,
+    /// Hex encoded SHA-256 of the input.
+    pub input_hash: String,
+}
+
+/// Solves `parts` of `day` on `input`, one record per part. When the input does not
+/// parse, every part gets the parse error.
+pub fn solve(day: &Day, input: &str, parts: &[Part]) -> Vec<Record> {
+    let input_hash = content_hash(input);
+    let results: Vec<(Outcome, Duration)> = match (day.solve_timed)(input, parts) {
+        Ok(answers) => answers
+            .into_iter()
+            .map(|(answer, duration)| {
+                let outcome = match answer {
+                    Ok(answer) => Outcome::Solved(answer),
+                    Err(AocError::Unsolved) => Outcome::Unsolved,
+                    Err(e) => Outcome::Error(e.to_string()),
+                };
+                (outcome, duration)
+            })
+            .collect(),
+        Err(e) => vec![(Outcome::Error(e.to_string()), Duration::ZERO); parts.len()],
+    };
+    parts
+        .iter()
+        .zip(results)
+        .map(|(&part, (outcome, duration))| Record {
+            day: day.number,
+            part,
+            outcome,
+            duration,
+            input_hash: input_hash.clone(),
+        })
+        .collect()
+}
+
+/// A solved part's answer, as a JSON number or string.
+#[derive(Serialize)]
+#[serde(untagged)]
+enum JsonAnswer<'a> {
+    Number(i64),
+    Text(&'a str),
+}
+
+/// The shape of a record in JSON.
+#[derive(Serialize)]
+struct JsonRecord<'a> {
+    day: u8,
+    part: u8,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<JsonAnswer<'a>>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    kind: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<&'a str>,
+    duration_us: u128,
+    input_hash: &'a str,
+}
+
+impl Record {
+    /// The record as one line of JSON, without the newline.
+    pub fn to_json(&self) -> String {
+        let (status, answer, kind, error) = match &self.outcome {
+            Outcome::Solved(Answer::Number(n)) => {
+                ("solved", Some(JsonAnswer::Number(*n)), Some("number"), None)
+            }
+            Outcome::Solved(Answer::Text(text)) => {
+                ("solved", Some(JsonAnswer::Text(text)), Some("text"), None)
+            }
+            Outcome::Unsolved => ("unsolved", None, None, None),
+            Outcome::Error(message) => ("error", None, None, Some(message.as_str())),
+        };
+        let json = JsonRecord {
+            day: self.day,
+            part: self.part.number(),
+            status,
+            answer,
+            kind,
+            error,
+            duration_us: self.duration.as_micros(),
+            input_hash: &self.input_hash,
+        };
+        serde_json::to_string(&json).expect("a record always serializes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(outcome: Outcome) -> Record {
+        Record {
+            day: 10,
+            part: Part::Two,
+            outcome,
+            duration: Duration::from_micros(1500),
+            input_hash: "ab12".to_string(),
+        }
+    }
+
+    #[test]
+    fn writes_each_outcome_as_json() {
+        assert_eq!(
+            record(Outcome::Solved(Answer::Number(-42))).to_json(),
+            r#"{"day":10,"part":2,"status":"solved","answer":-42,"type":"number","duration_us":1500,"input_hash":"ab12"}"#
+        );
+        assert_eq!(
+            record(Outcome::Solved(Answer::Text(
+                "#.\n\"a\"\\\u{1}".to_string()
+            )))
+            .to_json(),
+            r##"{"day":10,"part":2,"status":"solved","answer":"#.\n\"a\"\\\u0001","type":"text","duration_us":1500,"input_hash":"ab12"}"##
+        );
+        assert_eq!(
+            record(Outcome::Unsolved).to_json(),
+            r#"{"day":10,"part":2,"status":"unsolved","duration_us":1500,"input_hash":"ab12"}"#
+        );
+        assert_eq!(
+            record(Outcome::Error("no path".to_string())).to_json(),
+            r#"{"day":10,"part":2,"status":"error","error":"no path","duration_us":1500,"input_hash":"ab12"}"#
+        );
+    }
+}
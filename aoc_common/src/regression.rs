@@ -0,0 +1,224 @@
+//! Regression checks against answers recorded in a day's `answers.toml`:
+//!
+//! ```toml
+//! [example]
+//! part1 = 24000
+//! part2 = 45000
+//!
+//! [input]
+//! part1 = 66186
+//! ```
+//!
+//! Each table names an input file next to `answers.toml` and each key one part,
+//! except `[input]`, which is the real input kept in the cache. Parts left out are
+//! not checked, and files that are missing (real inputs are not always committed)
+//! are skipped. A part that is not solved yet fails like a wrong answer, so only
+//! record the parts that are solved.
+
+use crate::error::{AocError, Result};
+use crate::input::read_to_string;
+use crate::solution::{Part, Solver};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// The recorded answers for one input file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub input: PathBuf,
+    pub answers: Vec<(Part, String)>,
+}
+
+/// Name of the table holding the answers for the real input.
+pub const REAL_INPUT: &str = "input";
+
+/// Reads the fixtures recorded in `dir`, none when it has no `answers.toml`.
+/// `real_input` is where the day's real input is kept.
+pub fn load(dir: &Path, real_input: &Path) -> Result<Vec<Fixture>> {
+    let path = dir.join(ANSWERS_FILE);
+    if !path.exists() {
+        return Ok(vec![]);
+    }
+    let bad = |message: String| AocError::parse(format!("{}: {}", path.display(), message));
+    let table = read_to_string(&path)?
+        .parse::<toml::Table>()
+        .map_err(|e| bad(e.to_string()))?;
+
+    let mut fixtures = vec![];
+    for (file, parts) in table {
+        let parts = parts
+            .as_table()
+            .ok_or_else(|| bad(format!("[{}] should be a table of parts", file)))?;
+        let mut answers = vec![];
+        for (key, value) in parts {
+            let part = key
+                .strip_prefix("part")
+                .and_then(|n| n.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .ok_or_else(|| bad(format!("{}.{} is not a part", file, key)))?;
+            let answer = match value {
+                toml::Value::Integer(n) => n.to_string(),
+                toml::Value::String(s) => s.clone(),
+                _ => {
+                    return Err(bad(format!(
+                        "{}.{} should be a number or a string",
+                        file, key
+                    )))
+                }
+            };
+            answers.push((part, answer));
+        }
+        let input = if file == REAL_INPUT {
+            real_input.to_path_buf()
+        } else {
+            dir.join(file)
+        };
+        fixtures.push(Fixture { input, answers });
+    }
+    // Examples are quicker and say more when they fail, so the real input goes last.
+    fixtures.sort_by_key(|fixture| fixture.input == real_input);
+    Ok(fixtures)
+}
+
+/// A recorded answer that no longer comes out of the solution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    pub input: PathBuf,
+    pub part: Part,
+    pub expected: String,
+    pub actual: String,
+}
+
+impl fmt::Display for Mismatch {
+    /// Shows the answers line by line, so a single wrong row of a CRT screen stands out.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} part {}:", self.input.display(), self.part.number())?;
+        let expected: Vec<&str> = self.expected.lines().collect();
+        let actual: Vec<&str> = self.actual.lines().collect();
+        for i in 0..expected.len().max(actual.len()) {
+            match (expected.get(i), actual.get(i)) {
+                (Some(e), Some(a)) if e == a => writeln!(f, "    {}", e)?,
+                (e, a) => {
+                    if let Some(e) = e {
+                        writeln!(f, "  - {}", e)?;
+                    }
+                    if let Some(a) = a {
+                        writeln!(f, "  + {}", a)?;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What checking a directory's fixtures found.
+#[derive(Debug, Default)]
+pub struct Report {
+    pub passed: usize,
+    pub skipped: Vec<PathBuf>,
+    pub mismatches: Vec<Mismatch>,
+}
+
+/// Solves every fixture recorded in `dir` and compares the answers.
+pub fn check(dir: &Path, real_input: &Path, solve: Solver) -> Result<Report> {
+    let mut report = Report::default();
+    for fixture in load(dir, real_input)? {
+        let fixture_report = check_fixture(&fixture, solve)?;
+        report.passed += fixture_report.passed;
+        report.skipped.extend(fixture_report.skipped);
+        report.mismatches.extend(fixture_report.mismatches);
+    }
+    Ok(report)
+}
+
+/// Solves one fixture's input and compares the answers, skipping it when the input
+/// file is missing.
+pub fn check_fixture(fixture: &Fixture, solve: Solver) -> Result<Report> {
+    let mut report = Report::default();
+    if !fixture.input.exists() {
+        report.skipped.push(fixture.input.clone());
+        return Ok(report);
+    }
+    let input = read_to_string(&fixture.input)?;
+    let parts: Vec<Part> = fixture.answers.iter().map(|(part, _)| *part).collect();
+    let actual: Vec<String> = match solve(&input, &parts) {
+        Ok(answers) => answers
+            .into_iter()
+            .map(|answer| match answer {
+                Ok(answer) => answer.to_string(),
+                Err(AocError::Unsolved) => AocError::Unsolved.to_string(),
+                Err(e) => format!("error: {}", e),
+            })
+            .collect(),
+        Err(e) => vec![format!("error: {}", e); parts.len()],
+    };
+    for ((part, expected), actual) in fixture.answers.iter().zip(actual) {
+        if expected.trim_end() == actual.trim_end() {
+            report.passed += 1;
+        } else {
+            report.mismatches.push(Mismatch {
+                input: fixture.input.clone(),
+                part: *part,
+                expected: expected.clone(),
+                actual,
+            });
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Answer;
+    use std::{env, fs};
+
+    #[test]
+    fn mismatch_shows_only_changed_lines() {
+        let mismatch = Mismatch {
+            input: PathBuf::from("day_10/input"),
+            part: Part::Two,
+            expected: "#..#\n.##.".to_string(),
+            actual: "#..#\n.#..".to_string(),
+        };
+        assert_eq!(
+            mismatch.to_string(),
+            "day_10/input part 2:\n    #..#\n  - .##.\n  + .#..\n"
+        );
+    }
+
+    fn solve_part1_only(input: &str, parts: &[Part]) -> Result<Vec<Result<Answer>>> {
+        Ok(parts
+            .iter()
+            .map(|part| match part {
+                Part::One => Ok(Answer::from(input.trim().len())),
+                Part::Two => Err(AocError::Unsolved),
+            })
+            .collect())
+    }
+
+    #[test]
+    fn unsolved_part_with_a_recorded_answer_fails() {
+        let input = env::temp_dir().join(format!("aoc_common_regression_{}", std::process::id()));
+        fs::write(&input, "abc\n").unwrap();
+        let fixture = Fixture {
+            input: input.clone(),
+            answers: vec![(Part::One, "3".to_string()), (Part::Two, "7".to_string())],
+        };
+        let report = check_fixture(&fixture, solve_part1_only).unwrap();
+        fs::remove_file(&input).unwrap();
+
+        assert_eq!(report.passed, 1);
+        assert_eq!(
+            report.mismatches,
+            vec![Mismatch {
+                input,
+                part: Part::Two,
+                expected: "7".to_string(),
+                actual: "not solved yet".to_string(),
+            }]
+        );
+    }
+}
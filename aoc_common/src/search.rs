@@ -0,0 +1,334 @@
+//! Graph searches over anything that can list a node's successors: a closure
+//! `|node| -> impl IntoIterator<Item = N>` for unweighted graphs, or one yielding
+//! `(N, cost)` pairs for weighted ones. Paths include both ends.
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// Where one step from a node can lead.
+pub trait Successors<N> {
+    fn successors(&self, node: &N) -> Vec<N>;
+}
+
+impl<N, F, I> Successors<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    fn successors(&self, node: &N) -> Vec<N> {
+        self(node).into_iter().collect()
+    }
+}
+
+/// Where one step from a node can lead, and what the step costs.
+pub trait WeightedSuccessors<N> {
+    fn successors(&self, node: &N) -> Vec<(N, u64)>;
+}
+
+impl<N, F, I> WeightedSuccessors<N> for F
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    fn successors(&self, node: &N) -> Vec<(N, u64)> {
+        self(node).into_iter().collect()
+    }
+}
+
+/// Follows `parents` back from `goal` to a node without a parent, and returns the
+/// path from there to `goal`.
+pub fn reconstruct_path<N>(parents: &HashMap<N, N>, goal: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![goal];
+    while let Some(parent) = parents.get(path.last().unwrap()) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// The shortest path from any of `starts` to a node where `is_goal` holds, counting
+/// steps.
+pub fn bfs_multi<N, S, G, I>(starts: I, successors: &S, mut is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    S: Successors<N> + ?Sized,
+    G: FnMut(&N) -> bool,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, N> = HashMap::new();
+    let mut seen: HashMap<N, ()> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone(), ()).is_none() {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some(reconstruct_path(&parents, node));
+        }
+        for next in successors.successors(&node) {
+            if seen.insert(next.clone(), ()).is_none() {
+                parents.insert(next.clone(), node.clone());
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// The shortest path from `start` to a node where `is_goal` holds, counting steps.
+pub fn bfs<N, S, G>(start: N, successors: &S, is_goal: G) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    S: Successors<N> + ?Sized,
+    G: FnMut(&N) -> bool,
+{
+    bfs_multi([start], successors, is_goal)
+}
+
+/// How many steps every reachable node is from the nearest of `starts`.
+pub fn distances<N, S, I>(starts: I, successors: &S) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    S: Successors<N> + ?Sized,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !distances.contains_key(&start) {
+            distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let distance = distances[&node];
+        for next in successors.successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+/// The cheapest path from `start` to a node where `is_goal` holds, and its cost.
+/// `heuristic` must never overestimate the cost left to a goal; one that always
+/// says 0 makes this Dijkstra's algorithm.
+pub fn astar<N, S, H, G>(
+    start: N,
+    successors: &S,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    S: WeightedSuccessors<N> + ?Sized,
+    H: FnMut(&N) -> u64,
+    G: FnMut(&N) -> bool,
+{
+    // Nodes are kept in `nodes` and referred to by index, so they need not be Ord.
+    let mut nodes = vec![start.clone()];
+    let mut index: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut costs = vec![0];
+    let mut parents: Vec<Option<usize>> = vec![None];
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        if cost > costs[i] {
+            continue;
+        }
+        if is_goal(&nodes[i]) {
+            let mut path = vec![nodes[i].clone()];
+            let mut current = i;
+            while let Some(parent) = parents[current] {
+                path.push(nodes[parent].clone());
+                current = parent;
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        for (next, step) in successors.successors(&nodes[i]) {
+            let next_cost = cost + step;
+            let j = match index.get(&next) {
+                Some(&j) if costs[j] <= next_cost => continue,
+                Some(&j) => j,
+                None => {
+                    nodes.push(next.clone());
+                    costs.push(u64::MAX);
+                    parents.push(None);
+                    index.insert(next.clone(), nodes.len() - 1);
+                    nodes.len() - 1
+                }
+            };
+            costs[j] = next_cost;
+            parents[j] = Some(i);
+            queue.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+        }
+    }
+    None
+}
+
+/// The cheapest path from `start` to a node where `is_goal` holds, and its cost.
+pub fn dijkstra<N, S, G>(start: N, successors: &S, is_goal: G) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    S: WeightedSuccessors<N> + ?Sized,
+    G: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| 0, is_goal)
+}
+
+/// The cheapest cost from `start` to every reachable node.
+pub fn dijkstra_all<N, S>(start: N, successors: &S) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash,
+    S: WeightedSuccessors<N> + ?Sized,
+{
+    let mut nodes = vec![start.clone()];
+    let mut costs: HashMap<N, u64> = HashMap::from([(start, 0)]);
+    let mut queue = BinaryHeap::from([Reverse((0, 0))]);
+    while let Some(Reverse((cost, i))) = queue.pop() {
+        let node = nodes[i].clone();
+        if cost > costs[&node] {
+            continue;
+        }
+        for (next, step) in successors.successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|&known| known <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            nodes.push(next);
+            queue.push(Reverse((next_cost, nodes.len() - 1)));
+        }
+    }
+    costs
+}
+
+/// The cheapest cost between every pair of a small graph's nodes, by Floyd–Warshall.
+#[derive(Debug, Clone)]
+pub struct AllPairs<N> {
+    index: HashMap<N, usize>,
+    costs: Vec<Vec<Option<u64>>>,
+}
+
+impl<N> AllPairs<N>
+where
+    N: Clone + Eq + Hash,
+{
+    /// Steps that lead outside `nodes` are ignored.
+    pub fn new<S>(nodes: &[N], successors: &S) -> AllPairs<N>
+    where
+        S: WeightedSuccessors<N> + ?Sized,
+    {
+        let index: HashMap<N, usize> = nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.clone(), i))
+            .collect();
+        let n = nodes.len();
+        let mut costs = vec![vec![None; n]; n];
+        for (i, node) in nodes.iter().enumerate() {
+            costs[i][i] = Some(0);
+            for (next, step) in successors.successors(node) {
+                if let Some(&j) = index.get(&next) {
+                    costs[i][j] = Some(costs[i][j].map_or(step, |c: u64| c.min(step)));
+                }
+            }
+        }
+        for k in 0..n {
+            let from_k = costs[k].clone();
+            for row in costs.iter_mut() {
+                let Some(to_k) = row[k] else { continue };
+                for (cost, from_k) in row.iter_mut().zip(&from_k) {
+                    if let Some(from_k) = from_k {
+                        let through = to_k + from_k;
+                        if cost.is_none_or(|c| through < c) {
+                            *cost = Some(through);
+                        }
+                    }
+                }
+            }
+        }
+        AllPairs { index, costs }
+    }
+
+    /// The cheapest cost from `from` to `to`, if `to` can be reached at all.
+    pub fn get(&self, from: &N, to: &N) -> Option<u64> {
+        self.costs[*self.index.get(from)?][*self.index.get(to)?]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A 5x5 room with a wall down column 2 that has a gap in the bottom row.
+    fn open((x, y): &(i32, i32)) -> Vec<(i32, i32)> {
+        [(1, 0), (-1, 0), (0, 1), (0, -1)]
+            .iter()
+            .map(|(dx, dy)| (x + dx, y + dy))
+            .filter(|&(x, y)| (0..5).contains(&x) && (0..5).contains(&y) && (x != 2 || y == 4))
+            .collect()
+    }
+
+    #[test]
+    fn bfs_finds_a_shortest_path() {
+        let path = bfs((0, 0), &open, |&p| p == (4, 0)).unwrap();
+        assert_eq!(path.len() - 1, 12);
+        assert_eq!(path.first(), Some(&(0, 0)));
+        assert_eq!(path.last(), Some(&(4, 0)));
+        assert!(path.windows(2).all(|w| open(&w[0]).contains(&w[1])));
+        assert_eq!(bfs((0, 0), &open, |&p| p == (2, 0)), None);
+    }
+
+    #[test]
+    fn multi_source_and_distance_maps() {
+        let path = bfs_multi([(0, 0), (3, 4)], &open, |&p| p == (4, 0)).unwrap();
+        assert_eq!(path.len() - 1, 5);
+        let distances = distances([(0, 0)], &open);
+        assert_eq!(distances[&(0, 0)], 0);
+        assert_eq!(distances[&(4, 0)], 12);
+        assert_eq!(distances.len(), 5 * 5 - 4);
+    }
+
+    #[test]
+    fn weighted_searches_agree() {
+        // Going down costs 1, everything else 3.
+        let weighted = |p: &(i32, i32)| {
+            open(p)
+                .into_iter()
+                .map(|q| (q, if q.1 > p.1 { 1 } else { 3 }))
+                .collect::<Vec<_>>()
+        };
+        let (path, cost) = dijkstra((0, 0), &weighted, |&p| p == (4, 0)).unwrap();
+        assert_eq!(cost, 4 + 4 * 3 + 4 * 3);
+        assert_eq!(path.len(), 13);
+        let manhattan = |p: &(i32, i32)| ((4 - p.0).abs() + p.1.abs()) as u64;
+        let (_, astar_cost) = astar((0, 0), &weighted, manhattan, |&p| p == (4, 0)).unwrap();
+        assert_eq!(astar_cost, cost);
+        assert_eq!(dijkstra_all((0, 0), &weighted)[&(4, 0)], cost);
+
+        let nodes: Vec<(i32, i32)> = (0..5)
+            .flat_map(|x| (0..5).map(move |y| (x, y)))
+            .filter(|&(x, y)| x != 2 || y == 4)
+            .collect();
+        let all = AllPairs::new(&nodes, &weighted);
+        assert_eq!(all.get(&(0, 0), &(4, 0)), Some(cost));
+        assert_eq!(all.get(&(0, 0), &(0, 0)), Some(0));
+        assert_eq!(all.get(&(0, 0), &(2, 0)), None);
+    }
+
+    #[test]
+    fn reconstructs_paths_from_parents() {
+        let parents = HashMap::from([(3, 2), (2, 1)]);
+        assert_eq!(reconstruct_path(&parents, 3), [1, 2, 3]);
+        assert_eq!(reconstruct_path(&parents, 1), [1]);
+    }
+}
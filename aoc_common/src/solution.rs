@@ -0,0 +1,313 @@
+use crate::animate::Animation;
+use crate::differential::Rng;
+use crate::error::{AocError, Result};
+use crate::image::Image;
+use crate::input::InputSource;
+use crate::log;
+use crate::parallel;
+use std::fmt;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Numbers too big for an `i64` are kept as text, digit for digit, rather than
+/// wrapping around to a wrong answer.
+macro_rules! answer_from_number {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Answer {
+                    match i64::try_from(n) {
+                        Ok(n) => Answer::Number(n),
+                        Err(_) => Answer::Text(n.to_string()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+answer_from_number!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Answer {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Answer {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// A day's puzzle. The implementing type is the parsed input, and each part is
+/// computed from it without touching the input text again.
+pub trait Solution: Sized {
+    fn parse(input: &str) -> Result<Self>;
+
+    fn part1(&self) -> Result<Answer>;
+
+    fn part2(&self) -> Result<Answer>;
+
+    /// Plays the puzzle out frame by frame. Only the simulation days have one.
+    fn animate(&self, _animation: &mut Animation) -> Result<()> {
+        Err(AocError::usage("this day has no animation"))
+    }
+
+    /// A picture of the solved puzzle, for saving as an image file.
+    fn image(&self) -> Result<Image> {
+        Err(AocError::usage("this day has no picture"))
+    }
+
+    /// A random input the puzzle could have given, for checking the solution
+    /// against [`Solution::reference`] with [`differential::run`].
+    ///
+    /// [`differential::run`]: crate::differential::run
+    fn generate(_rng: &mut Rng) -> Result<String> {
+        Err(AocError::usage("this day has no input generator"))
+    }
+
+    /// Both answers worked out the slow, obvious way. Input the puzzle could not
+    /// have given is rejected with an error. Part 2 is `None` when the obvious way
+    /// cannot work it out for this input, so only part 1 is checked.
+    fn reference(_input: &str) -> Result<(Answer, Option<Answer>)> {
+        Err(AocError::usage("this day has no reference solution"))
+    }
+}
+
+/// One half of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+/// Parses `input` once and solves the requested parts, in order.
+pub fn solve_parts<S: Solution>(input: &str, parts: &[Part]) -> Result<Vec<Result<Answer>>> {
+    Ok(solve_timed::<S>(input, parts)?
+        .into_iter()
+        .map(|(answer, _)| answer)
+        .collect())
+}
+
+/// Like [`solve_parts`], with how long each part took.
+pub fn solve_timed<S: Solution>(
+    input: &str,
+    parts: &[Part],
+) -> Result<Vec<(Result<Answer>, Duration)>> {
+    let solution = S::parse(input)?;
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let start = Instant::now();
+            let answer = match part {
+                Part::One => solution.part1(),
+                Part::Two => solution.part2(),
+            };
+            (answer, start.elapsed())
+        })
+        .collect())
+}
+
+/// Solves the given parts of one day's input, see [`solve_parts`].
+pub type Solver = fn(&str, &[Part]) -> Result<Vec<Result<Answer>>>;
+
+/// Solves and times the given parts of one day's input, see [`solve_timed`].
+pub type TimedSolver = fn(&str, &[Part]) -> Result<Vec<(Result<Answer>, Duration)>>;
+
+/// How long each stage of solving one input took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Parses `input` and solves both parts, timing each stage on its own. A part that
+/// is not solved yet still gets a time; any other error stops the timing.
+pub fn time_parts<S: Solution>(input: &str) -> Result<Timings> {
+    let start = Instant::now();
+    let solution = S::parse(input)?;
+    let parse = start.elapsed();
+    let mut times = [Duration::ZERO; 2];
+    for (part, time) in Part::BOTH.iter().zip(times.iter_mut()) {
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => solution.part1(),
+            Part::Two => solution.part2(),
+        };
+        *time = start.elapsed();
+        match answer {
+            Ok(_) | Err(AocError::Unsolved) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(Timings {
+        parse,
+        part1: times[0],
+        part2: times[1],
+    })
+}
+
+/// Times one day's input, see [`time_parts`].
+pub type Timer = fn(&str) -> Result<Timings>;
+
+/// Parses `input` and plays it out with [`Solution::animate`].
+pub fn animate_input<S: Solution>(input: &str, animation: &mut Animation) -> Result<()> {
+    S::parse(input)?.animate(animation)
+}
+
+/// Animates one day's input, see [`animate_input`].
+pub type Animator = fn(&str, &mut Animation) -> Result<()>;
+
+/// Parses `input` and draws it with [`Solution::image`].
+pub fn image_input<S: Solution>(input: &str) -> Result<Image> {
+    S::parse(input)?.image()
+}
+
+/// Draws one day's input, see [`image_input`].
+pub type Painter = fn(&str) -> Result<Image>;
+
+/// Makes up an input for a day, see [`Solution::generate`].
+pub type Generator = fn(&mut Rng) -> Result<String>;
+
+/// Solves a day the obvious way, see [`Solution::reference`].
+pub type Reference = fn(&str) -> Result<(Answer, Option<Answer>)>;
+
+/// A day's solution with its parsed type erased, so every day can sit in one table.
+#[derive(Clone, Copy)]
+pub struct Day {
+    pub year: u32,
+    pub number: u8,
+    pub solve: Solver,
+    pub solve_timed: TimedSolver,
+    pub time: Timer,
+    pub animate: Animator,
+    pub image: Painter,
+    pub generate: Generator,
+    pub reference: Reference,
+}
+
+impl Day {
+    pub const fn new<S: Solution>(year: u32, number: u8) -> Day {
+        Day {
+            year,
+            number,
+            solve: solve_parts::<S>,
+            solve_timed: solve_timed::<S>,
+            time: time_parts::<S>,
+            animate: animate_input::<S>,
+            image: image_input::<S>,
+            generate: S::generate,
+            reference: S::reference,
+        }
+    }
+
+    /// Name of the day's crate, see [`crate_name`].
+    pub fn crate_name(&self) -> String {
+        crate_name(self.year, self.number)
+    }
+
+    /// The day's directory relative to the workspace root, see [`crate_dir`].
+    pub fn dir(&self) -> PathBuf {
+        crate_dir(self.year, self.number)
+    }
+}
+
+/// Name of the crate for `day` of `year`, e.g. `day_2022_07`. The year keeps the
+/// same day of different years apart in one workspace.
+pub fn crate_name(year: u32, day: u8) -> String {
+    format!("day_{}_{:02}", year, day)
+}
+
+/// Directory of the crate for `day` of `year` relative to the workspace root, e.g.
+/// `2022/day_07`, laid out like the cache.
+pub fn crate_dir(year: u32, day: u8) -> PathBuf {
+    PathBuf::from(year.to_string()).join(format!("day_{:02}", day))
+}
+
+/// Parses `input` and solves both parts.
+pub fn solve<S: Solution>(input: &str) -> Result<(Result<Answer>, Result<Answer>)> {
+    let solution = S::parse(input)?;
+    Ok((solution.part1(), solution.part2()))
+}
+
+/// Entry point for a day's binary: reads the input named on the command line,
+/// prints each solved part and exits non-zero on any error. Diagnostics are logged
+/// at the level named by [`AOC_LOG`](crate::log::LEVEL_VAR), and parallel work uses
+/// as many threads as [`AOC_THREADS`](crate::parallel::THREADS_VAR) says.
+pub fn main<S: Solution>() {
+    log::init_from_env();
+    parallel::init_from_env();
+    let source = InputSource::from_args();
+    if let Err(e) = run::<S>(&source) {
+        eprint!("{}", e.in_file(source.path()).report());
+        process::exit(1);
+    }
+}
+
+fn run<S: Solution>(source: &InputSource) -> Result<()> {
+    let input = source.read_to_string()?;
+    let (part1, part2) = solve::<S>(&input)?;
+    for (part, answer) in [(1, part1), (2, part2)] {
+        match answer {
+            Ok(answer) => println!("{}", answer),
+            Err(AocError::Unsolved) => crate::warn!("part {}: {}", part, AocError::Unsolved),
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn big_numbers_become_text_instead_of_wrapping() {
+        assert_eq!(Answer::from(42_u64), Answer::Number(42));
+        assert_eq!(Answer::from(-7_isize), Answer::Number(-7));
+        assert_eq!(Answer::from(i64::MAX as u64), Answer::Number(i64::MAX));
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Text("18446744073709551615".to_string())
+        );
+        assert_eq!(Answer::from(usize::MAX).to_string(), usize::MAX.to_string());
+    }
+}
@@ -0,0 +1,73 @@
+//! Kept apart from the unit tests so only this test binary counts its allocations.
+
+use aoc_common::budget::{check, enforce, memory_in_use, progress, CountingAllocator, Limits};
+use aoc_common::AocError;
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// About how often the watchdog looks at the clock.
+const POLL: Duration = Duration::from_millis(10);
+
+/// Waits for the watchdog to raise the flag, failing the test if it never does.
+fn stopped() -> AocError {
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(5) {
+        if let Err(e) = check() {
+            return e;
+        }
+        thread::sleep(POLL);
+    }
+    panic!("the watchdog never stopped the work");
+}
+
+// One test, as the budget is global to the process.
+#[test]
+fn stops_work_that_goes_over_either_limit() {
+    {
+        let _watchdog = enforce("nothing", Limits::default());
+        thread::sleep(POLL * 3);
+        assert!(check().is_ok());
+    }
+
+    {
+        let limits = Limits {
+            time: Some(Duration::from_millis(20)),
+            memory: None,
+        };
+        let _watchdog = enforce("slow", limits);
+        progress("line 12 of 40");
+        match stopped() {
+            AocError::OverBudget {
+                reason, progress, ..
+            } => {
+                assert_eq!(reason, "went over its time limit of 20ms");
+                assert_eq!(progress.as_deref(), Some("line 12 of 40"));
+            }
+            e => panic!("unexpected error {:?}", e),
+        }
+    }
+    assert!(check().is_ok(), "dropping the watchdog clears the flag");
+
+    let limits = Limits {
+        time: None,
+        memory: Some(memory_in_use().unwrap() + (1 << 20)),
+    };
+    let _watchdog = enforce("hungry", limits);
+    let big = vec![1u8; 8 << 20];
+    // The allocation itself raises the flag, without waiting for the watchdog.
+    match check().unwrap_err() {
+        AocError::OverBudget {
+            reason,
+            progress,
+            peak_memory,
+        } => {
+            assert!(reason.starts_with("went over its memory limit of "));
+            assert_eq!(progress, None);
+            assert!(peak_memory.unwrap() >= big.len());
+        }
+        e => panic!("unexpected error {:?}", e),
+    }
+}
@@ -0,0 +1,76 @@
+use crate::error::HelperError;
+use std::process::Command;
+
+fn describe(program: &str, args: &[&str]) -> String {
+    std::iter::once(program)
+        .chain(args.iter().copied())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Runs cargo with its output going straight to the terminal.
+pub fn cargo_inherited(args: &[&str]) -> Result<(), HelperError> {
+    let command = describe("cargo", args);
+    let status = Command::new("cargo")
+        .args(args)
+        .status()
+        .map_err(|source| HelperError::Spawn {
+            command: command.clone(),
+            source,
+        })?;
+    if !status.success() {
+        return Err(HelperError::Failed {
+            command,
+            stderr: String::new(),
+        });
+    }
+    Ok(())
+}
+
+/// Solves a day through the `aoc` runner.
+pub fn run(year: u32, day: u8, part: Option<u8>) -> Result<(), HelperError> {
+    let year = year.to_string();
+    let day = day.to_string();
+    let mut args = vec![
+        "run",
+        "--release",
+        "--quiet",
+        "-p",
+        "aoc",
+        "--",
+        "run",
+        "--year",
+        &year,
+        "--day",
+        &day,
+    ];
+    let part = part.map(|p| p.to_string());
+    if let Some(part) = &part {
+        args.extend(["--part", part]);
+    }
+    cargo_inherited(&args)
+}
+
+/// Times days through the `aoc` runner, which keeps the history of timings.
+pub fn bench(year: u32, day: Option<u8>, runs: usize) -> Result<(), HelperError> {
+    let year = year.to_string();
+    let runs = runs.to_string();
+    let mut args = vec![
+        "run",
+        "--release",
+        "--quiet",
+        "-p",
+        "aoc",
+        "--",
+        "bench",
+        "--year",
+        &year,
+        "--runs",
+        &runs,
+    ];
+    let day = day.map(|d| d.to_string());
+    if let Some(day) = &day {
+        args.extend(["--day", day]);
+    }
+    cargo_inherited(&args)
+}
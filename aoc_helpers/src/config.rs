@@ -0,0 +1,14 @@
+use std::env;
+use std::path::PathBuf;
+
+/// Where the helpers look for the user's own settings: `$AOC_CONFIG_DIR`, otherwise
+/// `aoc` in the usual config directory.
+pub fn config_dir() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("AOC_CONFIG_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+    env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .map(|dir| dir.join("aoc"))
+}
@@ -0,0 +1,141 @@
+use crate::session::{Session, CREDENTIALS_FILE, SESSION_VAR};
+use crate::submit::SubmitError;
+use aoc_common::AocError;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// Everything that can stop one of the helper commands.
+#[derive(Debug)]
+pub enum HelperError {
+    /// A command could not be started at all.
+    Spawn {
+        command: String,
+        source: io::Error,
+    },
+    /// A command ran but failed. `stderr` is empty when it was already shown.
+    Failed {
+        command: String,
+        stderr: String,
+    },
+    Io {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The day is already set up and `--force` was not given.
+    AlreadyExists(PathBuf),
+    UnknownTemplate {
+        name: String,
+        available: Vec<String>,
+    },
+    BadTemplate {
+        path: PathBuf,
+        message: String,
+    },
+    /// No session cookie for the year. `path` is the credentials file looked in.
+    MissingCookie {
+        year: u32,
+        path: Option<PathBuf>,
+    },
+    /// A session cookie that cannot be right, from `source`.
+    BadCookie {
+        source: String,
+        message: String,
+    },
+    BadCredentials {
+        path: PathBuf,
+        message: String,
+    },
+    /// The site no longer knows the session.
+    LoggedOut(Session),
+    /// Reading or writing the cache failed.
+    Cache(AocError),
+    Http(reqwest::Error),
+    /// The site answered, but not with what was asked for.
+    BadResponse {
+        url: String,
+        status: u16,
+        body: String,
+    },
+    Submit(SubmitError),
+}
+
+impl fmt::Display for HelperError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HelperError::Spawn { command, source } => {
+                write!(f, "could not run `{}`: {}", command, source)
+            }
+            HelperError::Failed { command, stderr } if stderr.is_empty() => {
+                write!(f, "`{}` failed", command)
+            }
+            HelperError::Failed { command, stderr } => {
+                write!(f, "`{}` failed:\n{}", command, stderr.trim_end())
+            }
+            HelperError::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            HelperError::AlreadyExists(path) => write!(
+                f,
+                "{} already exists, use --force to fill in what is missing",
+                path.display()
+            ),
+            HelperError::UnknownTemplate { name, available } => write!(
+                f,
+                "there is no template called {}, try one of: {}",
+                name,
+                available.join(", ")
+            ),
+            HelperError::BadTemplate { path, message } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            HelperError::MissingCookie { year, path } => {
+                write!(f, "no session cookie for {}: set {}", year, SESSION_VAR)?;
+                match path {
+                    Some(path) => write!(f, " or add one to {}", path.display()),
+                    None => write!(f, " or add one to {}", CREDENTIALS_FILE),
+                }
+            }
+            HelperError::BadCookie { source, message } => {
+                write!(
+                    f,
+                    "the session cookie from {} is no good: {}",
+                    source, message
+                )
+            }
+            HelperError::BadCredentials { path, message } => {
+                write!(f, "{}: {}", path.display(), message)
+            }
+            HelperError::LoggedOut(session) => write!(
+                f,
+                "the site does not know {} any more, log in again and copy the new one",
+                session
+            ),
+            HelperError::Cache(e) => write!(f, "{}", e),
+            HelperError::Http(e) => write!(f, "could not reach the site: {}", e),
+            HelperError::BadResponse { url, status, body } => {
+                let first_line = body.lines().next().unwrap_or("").trim();
+                write!(f, "{} answered {}: {}", url, status, first_line)
+            }
+            HelperError::Submit(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for HelperError {}
+
+impl From<reqwest::Error> for HelperError {
+    fn from(e: reqwest::Error) -> HelperError {
+        HelperError::Http(e)
+    }
+}
+
+impl From<AocError> for HelperError {
+    fn from(e: AocError) -> HelperError {
+        HelperError::Cache(e)
+    }
+}
+
+impl From<SubmitError> for HelperError {
+    fn from(e: SubmitError) -> HelperError {
+        HelperError::Submit(e)
+    }
+}
@@ -0,0 +1,103 @@
+use crate::error::HelperError;
+use crate::session::{self, Session};
+use aoc_common::cache::{Cache, Entry};
+use std::path::PathBuf;
+
+/// Fetches a page of the site, refusing anything that is not what was asked for:
+/// error statuses, empty bodies and, when logged in, the page asking to log in.
+#[tokio::main]
+pub(crate) async fn download(url: &str, session: Option<&Session>) -> Result<String, HelperError> {
+    let client = reqwest::Client::new();
+    let mut request = client.get(url);
+    if let Some(session) = session {
+        request = request.header("Cookie", session.header());
+    }
+    let response = request.send().await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if let Some(session) = session.filter(|_| session::is_logged_out_page(&body)) {
+        return Err(HelperError::LoggedOut(session.clone()));
+    }
+    if !status.is_success() || body.trim().is_empty() || session::is_logged_out_page(&body) {
+        return Err(HelperError::BadResponse {
+            url: url.to_string(),
+            status: status.as_u16(),
+            body,
+        });
+    }
+    Ok(body)
+}
+
+fn input_url(base_url: &str, year: u32, day: u8) -> String {
+    format!(
+        "{base_url}/{year}/day/{day}/input",
+        base_url = base_url.trim_end_matches('/'),
+        year = year,
+        day = day
+    )
+}
+
+/// Makes sure the day's input is in the cache. An input is only ever downloaded
+/// once; after that the cached copy is used. The session is checked before
+/// downloading, so a stale one is reported as such.
+pub fn get_input(base_url: &str, year: u32, day: u8) -> Result<PathBuf, HelperError> {
+    let cache = Cache::in_workspace(".");
+    if let Some(path) = cache.get(year, day, Entry::Input) {
+        println!("{} is already cached", path.display());
+        return Ok(path);
+    }
+    let url = input_url(base_url, year, day);
+    let session = session::find(year)?;
+    session::check(base_url, &session)?;
+    let input = download(&url, Some(&session))?;
+    Ok(cache.store(year, day, Entry::Input, &input, &url)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::mock_server;
+
+    const LOGGED_OUT_PAGE: &str =
+        "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+
+    #[test]
+    fn returns_the_input() {
+        let (url, request) = mock_server(200, "1\n2\n3\n".to_string());
+        let session = Session::parse("abc", "test").unwrap();
+        let input = download(&input_url(&url, 2022, 3), Some(&session)).unwrap();
+        assert_eq!(input, "1\n2\n3\n");
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /2022/day/3/input "));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn refuses_error_pages() {
+        for (status, body) in [
+            (400, LOGGED_OUT_PAGE),
+            (200, LOGGED_OUT_PAGE),
+            (404, "Not Found"),
+            (200, ""),
+        ] {
+            let (url, _request) = mock_server(status, body.to_string());
+            match download(&input_url(&url, 2022, 3), None) {
+                Err(HelperError::BadResponse { status: got, .. }) => assert_eq!(got, status),
+                other => panic!(
+                    "expected {} {:?} to be refused, got {:?}",
+                    status, body, other
+                ),
+            }
+        }
+    }
+
+    #[test]
+    fn reports_a_stale_session() {
+        let session = Session::parse("abc", "test").unwrap();
+        let (url, _request) = mock_server(400, LOGGED_OUT_PAGE.to_string());
+        match download(&input_url(&url, 2022, 3), Some(&session)) {
+            Err(HelperError::LoggedOut(stale)) => assert_eq!(stale, session),
+            other => panic!("expected the session to be stale, got {:?}", other),
+        }
+    }
+}
@@ -1,109 +1,242 @@
-use clap::Parser;
-use reqwest; // 0.10.0
-use std::env;
-use std::fs;
-use std::process::Command;
-use tokio; // 0.2.6
-
-#[derive(Parser, Default, Debug)]
-/// Set up a new Rust project for an AOC problem.
+use clap::{Args, Parser, Subcommand};
+use error::HelperError;
+use std::path::Path;
+use std::process;
+
+mod cargo;
+mod config;
+mod error;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod puzzle;
+mod scaffold;
+mod session;
+mod status;
+mod submit;
+mod template;
+
+#[derive(Parser, Debug)]
+/// Helpers for working on AOC problems.
 #[clap(author = "Oliver Sargent", long_about=None)]
 struct Arguments {
+    /// site to talk to, e.g. a local mock server
+    #[clap(long, default_value = "https://adventofcode.com", global = true)]
+    base_url: String,
+    #[clap(subcommand)]
+    command: HelperCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum HelperCommand {
+    /// Set up a new Rust project for an AOC problem and fetch its input
+    New(NewArguments),
+    /// Download the input for a day that is already set up
+    Fetch(DayArguments),
+    /// Download the puzzle page and turn its examples into fixtures
+    Puzzle(PuzzleArguments),
+    /// Submit an answer, unless it was already rejected
+    Submit(SubmitArguments),
+    /// Solve a day with the `aoc` runner
+    Run(RunArguments),
+    /// Time days with the `aoc` runner and flag any that got slower
+    Bench(BenchArguments),
+    /// Show which days have inputs, recorded answers and rejected answers
+    Status(YearArguments),
+    /// List the templates `new` can start a day from
+    Templates,
+    /// Manage the session cookie the site knows you by
+    Login(LoginArguments),
+}
+
+#[derive(Args, Debug)]
+struct LoginArguments {
+    #[clap(subcommand)]
+    command: LoginCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum LoginCommand {
+    /// Check that the session cookie for a year is still logged in
+    Check(YearArguments),
+}
+
+#[derive(Args, Debug)]
+struct NewArguments {
     /// day of problem to set up
     #[clap(short, long)]
     day: u8,
     /// year of problem to set up
     #[clap(short, long, default_value_t = 2022)]
     year: u32,
+    /// fill in whatever an existing day is missing
+    #[clap(long)]
+    force: bool,
+    /// template to start the solution from, see `templates`
+    #[clap(short, long, default_value = template::DEFAULT_TEMPLATE)]
+    template: String,
 }
 
-const TEMPLATE: &str = r#"use std::env;
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+#[derive(Args, Debug)]
+struct DayArguments {
+    /// day of problem
+    #[clap(short, long)]
+    day: u8,
+    /// year of problem
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
+}
 
-// The output is wrapped in a Result to allow matching on errors
-// Returns an Iterator to the Reader of the lines of the file.
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+#[derive(Args, Debug)]
+struct PuzzleArguments {
+    /// day of problem
+    #[clap(short, long)]
+    day: u8,
+    /// year of problem
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
+    /// download the page again, e.g. once part two is unlocked
+    #[clap(long)]
+    refresh: bool,
 }
 
-#[allow(dead_code)]
-fn parse_line(line: String) {
+#[derive(Args, Debug)]
+struct SubmitArguments {
+    /// day of problem to answer
+    #[clap(short, long)]
+    day: u8,
+    /// part of problem to answer
+    #[clap(short, long)]
+    part: u8,
+    /// answer to send
+    #[clap(short, long)]
+    answer: String,
+    /// year of problem to answer
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
 }
 
-fn main() {
-    if let Some(arg1) = env::args().nth(1) {
-        // File hosts must exist in current path before this produces output
-        if let Ok(lines) = read_lines(arg1) {
-            // Consumes the iterator, returns an (Optional) String
-            let r = lines
-                .filter_map(|x| x.ok())
-                .collect::<Vec<String>>()
-            println!("{}", r)
-        }
-    }
+#[derive(Args, Debug)]
+struct RunArguments {
+    /// day to run
+    #[clap(short, long)]
+    day: u8,
+    /// part to run, both when left out
+    #[clap(short, long)]
+    part: Option<u8>,
+    /// year of problem to run
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
+}
+
+#[derive(Args, Debug)]
+struct BenchArguments {
+    /// day to time, every day when left out
+    #[clap(short, long)]
+    day: Option<u8>,
+    /// how many times to solve each input
+    #[clap(short, long, default_value_t = 10)]
+    runs: usize,
+    /// year of problems to time
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
+}
+
+#[derive(Args, Debug)]
+struct YearArguments {
+    /// year of problems
+    #[clap(short, long, default_value_t = 2022)]
+    year: u32,
+}
+
+/// The workspace the helpers were built in, which new days are added to.
+fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the helpers live inside the workspace")
 }
-"#;
-
-#[tokio::main]
-async fn get_input(year: u32, day: u8) -> Result<(), reqwest::Error> {
-    let client = reqwest::Client::new();
-    let cookie = env::var("AOC_SESSION_COOKIE").unwrap_or("none".to_string());
-    let res = client
-        .get(format!(
-            "https://adventofcode.com/{year}/day/{day}/input",
-            year = year,
-            day = day
-        ))
-        .header("Cookie", cookie)
-        .send()
-        .await?
-        .text()
-        .await;
-
-    fs::write(
-        format!("day_{day}/input", day = pad_day_with_zero(day)),
-        res.unwrap(),
-    )
-    .expect("Unable to write file");
 
+fn new_day(base_url: &str, args: NewArguments) -> Result<(), HelperError> {
+    let template = template::find(&args.template)?;
+    scaffold::new_day(workspace_root(), args.year, args.day, &template, args.force)?;
+    fetch::get_input(base_url, args.year, args.day)?;
+    puzzle_fixtures(base_url, args.year, args.day, false)?;
+    println!("done!");
     Ok(())
 }
 
-fn pad_day_with_zero(day: u8) -> String {
-    if day < 10 {
-        format!("0{day}", day = day)
-    } else {
-        format!("{day}", day = day)
+fn puzzle_fixtures(base_url: &str, year: u32, day: u8, refresh: bool) -> Result<(), HelperError> {
+    puzzle::get_puzzle(base_url, year, day, refresh)?;
+    let page = puzzle::write_fixtures(year, day)?;
+    println!("found {} example(s)", page.examples.len());
+    for (i, answer) in page.answers.iter().enumerate() {
+        if let Some(answer) = answer {
+            println!("part {} example answer: {}", i + 1, answer);
+        }
     }
+    Ok(())
+}
+
+fn submit_answer(base_url: &str, args: SubmitArguments) -> Result<(), HelperError> {
+    let mut ledger = submit::Ledger::open(submit::LEDGER)?;
+    let session = session::find(args.year)?;
+    session::check(base_url, &session)?;
+    let verdict = submit::submit(
+        &mut ledger,
+        base_url,
+        &session.header(),
+        args.year,
+        args.day,
+        args.part,
+        &args.answer,
+    )?;
+    println!("{}", verdict);
+    Ok(())
 }
 
-fn copy_template_to_main(day: u8) {
-    fs::write(
-        format!("day_{day}/src/main.rs", day = pad_day_with_zero(day)),
-        TEMPLATE,
-    )
-    .expect("Unable to write template");
+fn check_login(base_url: &str, year: u32) -> Result<(), HelperError> {
+    let session = session::find(year)?;
+    session::check(base_url, &session)?;
+    println!("logged in for {} with {}", year, session);
+    Ok(())
 }
 
-fn set_up_new_project(day: u8) {
-    let mut cargo_new = Command::new("cargo");
-    cargo_new
-        .arg("new")
-        .arg(format!("day_{day}", day = pad_day_with_zero(day)));
-    let output = cargo_new.output().expect("failed to execute process");
-    println!("{:?}", output);
+fn list_templates() -> Result<(), HelperError> {
+    for template in template::available()? {
+        println!("{}", template.name);
+    }
+    Ok(())
 }
 
 fn main() {
     let args = Arguments::parse();
-    set_up_new_project(args.day);
-    copy_template_to_main(args.day);
-    get_input(args.year, args.day).expect("Could not get input!");
-    println!("{}", "done!")
+    let base_url = &args.base_url;
+    let result = match args.command {
+        HelperCommand::New(new_args) => new_day(base_url, new_args),
+        HelperCommand::Fetch(day_args) => {
+            fetch::get_input(base_url, day_args.year, day_args.day).map(|_| ())
+        }
+        HelperCommand::Puzzle(puzzle_args) => puzzle_fixtures(
+            base_url,
+            puzzle_args.year,
+            puzzle_args.day,
+            puzzle_args.refresh,
+        ),
+        HelperCommand::Submit(submit_args) => submit_answer(base_url, submit_args),
+        HelperCommand::Run(run_args) => cargo::run(run_args.year, run_args.day, run_args.part),
+        HelperCommand::Bench(bench_args) => {
+            cargo::bench(bench_args.year, bench_args.day, bench_args.runs)
+        }
+        HelperCommand::Status(year_args) => submit::Ledger::open(submit::LEDGER)
+            .map(|ledger| status::print_status(year_args.year, &ledger))
+            .map_err(HelperError::from),
+        HelperCommand::Templates => list_templates(),
+        HelperCommand::Login(LoginArguments {
+            command: LoginCommand::Check(year_args),
+        }) => check_login(base_url, year_args.year),
+    };
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,270 @@
+use crate::error::HelperError;
+use crate::fetch::download;
+use crate::session;
+use aoc_common::cache::{Cache, Entry};
+use aoc_common::regression::ANSWERS_FILE;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// What the puzzle page gives away: every `<pre><code>` block, and for each part
+/// the emphasised value, which is the answer for the example.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PuzzlePage {
+    pub examples: Vec<String>,
+    /// The first example block of each part's description.
+    pub part_examples: Vec<Option<String>>,
+    pub answers: Vec<Option<String>>,
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    unescape(&text)
+}
+
+/// The contents of every `open` ... `close` section of `html`, in order.
+fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = vec![];
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let inner = &rest[start + open.len()..];
+        match inner.find(close) {
+            Some(end) => {
+                found.push(&inner[..end]);
+                rest = &inner[end + close.len()..];
+            }
+            None => break,
+        }
+    }
+    found
+}
+
+fn code_blocks(html: &str) -> Vec<String> {
+    sections(html, "<pre><code>", "</code></pre>")
+        .into_iter()
+        .map(strip_tags)
+        .collect()
+}
+
+/// The last emphasised bit of code in a part's description, which is where the
+/// puzzle states the example's answer.
+fn emphasised_answer(article: &str) -> Option<String> {
+    let mut candidates: Vec<(usize, &str)> = vec![];
+    for (open, close) in [
+        ("<code><em>", "</em></code>"),
+        ("<em><code>", "</code></em>"),
+    ] {
+        let mut offset = 0;
+        for section in sections(article, open, close) {
+            let position = offset + article[offset..].find(section).unwrap_or(0);
+            offset = position + section.len();
+            candidates.push((position, section));
+        }
+    }
+    candidates
+        .into_iter()
+        .max_by_key(|(position, _)| *position)
+        .map(|(_, answer)| strip_tags(answer).trim().to_string())
+}
+
+pub fn parse_puzzle(html: &str) -> PuzzlePage {
+    let articles = sections(html, "<article", "</article>");
+    PuzzlePage {
+        examples: code_blocks(html),
+        part_examples: articles
+            .iter()
+            .map(|article| code_blocks(article).into_iter().next())
+            .collect(),
+        answers: articles
+            .iter()
+            .map(|article| emphasised_answer(article))
+            .collect(),
+    }
+}
+
+fn toml_value(answer: &str) -> String {
+    match answer.parse::<i64>() {
+        Ok(n) => n.to_string(),
+        Err(_) => toml::Value::String(answer.to_string()).to_string(),
+    }
+}
+
+/// Adds the example answers to `answers.toml` text without touching anything that is
+/// already recorded, comments included.
+fn add_example_answers(contents: &str, answers: &[Option<String>]) -> String {
+    let mut lines: Vec<String> = contents.lines().map(|l| l.to_string()).collect();
+    let header = match lines.iter().position(|l| l.trim() == "[example]") {
+        Some(header) => header,
+        None => {
+            if lines.last().is_some_and(|l| !l.trim().is_empty()) {
+                lines.push(String::new());
+            }
+            lines.push("[example]".to_string());
+            lines.len() - 1
+        }
+    };
+    let table_end = lines[header + 1..]
+        .iter()
+        .position(|l| l.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| header + 1 + i);
+    let mut insert_at = header + 1;
+    for (i, answer) in answers.iter().enumerate() {
+        let key = format!("part{}", i + 1);
+        let existing = lines[header + 1..table_end]
+            .iter()
+            .position(|l| l.split('=').next().map(str::trim) == Some(key.as_str()));
+        match (existing, answer) {
+            (Some(offset), _) => insert_at = header + 2 + offset,
+            (None, Some(answer)) => {
+                lines.insert(insert_at, format!("{} = {}", key, toml_value(answer)));
+                insert_at += 1;
+            }
+            (None, None) => {}
+        }
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Downloads the day's puzzle page into the cache, unless it is there already and
+/// `refresh` is not given. Part two only shows up once part one is solved.
+pub fn get_puzzle(
+    base_url: &str,
+    year: u32,
+    day: u8,
+    refresh: bool,
+) -> Result<PathBuf, HelperError> {
+    let cache = Cache::in_workspace(".");
+    if let Some(path) = cache.get(year, day, Entry::Puzzle).filter(|_| !refresh) {
+        return Ok(path);
+    }
+    let url = format!(
+        "{base_url}/{year}/day/{day}",
+        base_url = base_url.trim_end_matches('/'),
+        year = year,
+        day = day
+    );
+    // The description is public, the cookie only adds part two.
+    let html = download(&url, session::find(year).ok().as_ref())?;
+    Ok(cache.store(year, day, Entry::Puzzle, &html, &url)?)
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), HelperError> {
+    fs::write(path, contents).map_err(|source| HelperError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// Saves every example from the cached puzzle page next to it, and gives the day's
+/// crate an `example` and its answers in `answers.toml`.
+pub fn write_fixtures(year: u32, day: u8) -> Result<PuzzlePage, HelperError> {
+    let cache = Cache::in_workspace(".");
+    let puzzle = cache.path(year, day, Entry::Puzzle);
+    let html = fs::read_to_string(&puzzle).map_err(|source| HelperError::Io {
+        path: puzzle.clone(),
+        source,
+    })?;
+    let page = parse_puzzle(&html);
+
+    let examples_dir = cache.examples_dir(year, day);
+    fs::create_dir_all(&examples_dir).map_err(|source| HelperError::Io {
+        path: examples_dir.clone(),
+        source,
+    })?;
+    for (i, example) in page.examples.iter().enumerate() {
+        write(&examples_dir.join(format!("example_{}", i + 1)), example)?;
+    }
+
+    let crate_dir = aoc_common::crate_dir(year, day);
+    if !crate_dir.is_dir() {
+        return Ok(page);
+    }
+    let example = crate_dir.join("example");
+    match page.part_examples.first() {
+        Some(Some(block)) if !example.exists() => write(&example, block)?,
+        _ => {}
+    }
+    let answers_path = crate_dir.join(ANSWERS_FILE);
+    let contents = fs::read_to_string(&answers_path).unwrap_or_default();
+    let updated = add_example_answers(&contents, &page.answers);
+    if updated != contents {
+        write(&answers_path, &updated)?;
+    }
+    Ok(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Calorie Counting ---</h2>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+4000
+</code></pre>
+<p>The Elf carrying the most is carrying <code><em>24000</em></code> Calories, see <code>x &lt; y</code>.</p>
+</article>
+<p>Your puzzle answer was <code>66186</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a<em>b</em> &amp; c
+</code></pre>
+<p>In the example above, the top three carry <code><em>2</em></code> Elves, then <em><code>45000</code></em> in total, <em>really</em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn extracts_examples_and_answers() {
+        let page = parse_puzzle(PAGE);
+        assert_eq!(
+            page.examples,
+            vec!["1000\n2000\n\n4000\n".to_string(), "ab & c\n".to_string()]
+        );
+        assert_eq!(
+            page.part_examples,
+            vec![
+                Some("1000\n2000\n\n4000\n".to_string()),
+                Some("ab & c\n".to_string())
+            ]
+        );
+        assert_eq!(
+            page.answers,
+            vec![Some("24000".to_string()), Some("45000".to_string())]
+        );
+    }
+
+    #[test]
+    fn only_adds_missing_example_answers() {
+        let answers = [Some("24000".to_string()), Some("ab".to_string())];
+        assert_eq!(
+            add_example_answers("", &answers),
+            "[example]\npart1 = 24000\npart2 = \"ab\"\n"
+        );
+        assert_eq!(
+            add_example_answers("# kept\n[input]\npart1 = 5\n", &answers[..1]),
+            "# kept\n[input]\npart1 = 5\n\n[example]\npart1 = 24000\n"
+        );
+        assert_eq!(
+            add_example_answers("[example]\npart1 = 3\n\n[input]\npart1 = 5\n", &answers),
+            "[example]\npart1 = 3\npart2 = \"ab\"\n\n[input]\npart1 = 5\n"
+        );
+    }
+}
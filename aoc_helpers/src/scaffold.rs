@@ -0,0 +1,228 @@
+use crate::error::HelperError;
+use crate::template::Template;
+use aoc_common::{crate_dir, crate_name};
+use std::fs;
+use std::path::Path;
+
+fn read(path: &Path) -> Result<String, HelperError> {
+    fs::read_to_string(path).map_err(|source| HelperError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), HelperError> {
+    fs::write(path, contents).map_err(|source| HelperError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+/// The edition every crate in the workspace is written for.
+const EDITION: &str = "2021";
+
+/// Adds `dependency`, a line such as `regex = "1"`, to the day's manifest unless a
+/// dependency of that name is already there.
+fn add_dependency(root: &Path, year: u32, day: u8, dependency: &str) -> Result<(), HelperError> {
+    let manifest = root.join(crate_dir(year, day)).join("Cargo.toml");
+    let contents = read(&manifest)?;
+    let name = dependency.split('=').next().unwrap_or(dependency).trim();
+    if contents
+        .lines()
+        .any(|line| line.split('=').next().map(str::trim) == Some(name))
+    {
+        return Ok(());
+    }
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let table = match lines
+        .iter()
+        .position(|line| line.trim() == "[dependencies]")
+    {
+        Some(table) => table,
+        None => {
+            lines.push("[dependencies]");
+            lines.len() - 1
+        }
+    };
+    // After the table's last entry, so dependencies stay in the order they came.
+    let mut end = lines[table + 1..]
+        .iter()
+        .position(|line| line.trim_start().starts_with('['))
+        .map_or(lines.len(), |i| table + 1 + i);
+    while end > table + 1 && lines[end - 1].trim().is_empty() {
+        end -= 1;
+    }
+    lines.insert(end, dependency);
+    write(&manifest, &(lines.join("\n") + "\n"))
+}
+
+/// Writes `contents` to `path` unless something is already there, so a solution
+/// in progress is never replaced by the template.
+fn write_new(path: &Path, contents: &str) -> Result<(), HelperError> {
+    if path.exists() {
+        println!("{} already exists, leaving it alone", path.display());
+        return Ok(());
+    }
+    write(path, contents)
+}
+
+fn copy_template_to_main(
+    root: &Path,
+    year: u32,
+    day: u8,
+    lib_source: &str,
+) -> Result<(), HelperError> {
+    let name = crate_name(year, day);
+    let src = root.join(crate_dir(year, day)).join("src");
+    let lib = src.join("lib.rs");
+    let main = src.join("main.rs");
+    write_new(&lib, lib_source)?;
+    write_new(
+        &main,
+        &format!(
+            "fn main() {{\n    aoc_common::main::<{name}::Puzzle>()\n}}\n",
+            name = name
+        ),
+    )
+}
+
+/// Inserts `line` before the first line of `path` equal to `anchor`, or at the end
+/// of the file without an anchor. Does nothing if `needle` is already there.
+fn insert_line(
+    path: &Path,
+    needle: &str,
+    anchor: Option<&str>,
+    line: &str,
+) -> Result<(), HelperError> {
+    let contents = read(path)?;
+    if contents.contains(needle) {
+        return Ok(());
+    }
+    let mut lines: Vec<&str> = contents.lines().collect();
+    let index = anchor
+        .and_then(|anchor| lines.iter().position(|l| *l == anchor))
+        .unwrap_or(lines.len());
+    lines.insert(index, line);
+    write(path, &(lines.join("\n") + "\n"))
+}
+
+/// Adds the new day to the members of the workspace at `root` and to the `aoc`
+/// runner.
+pub fn register_in_workspace(root: &Path, year: u32, day: u8) -> Result<(), HelperError> {
+    let name = crate_name(year, day);
+    // Manifests want forward slashes whatever the platform.
+    let dir = format!("{}/day_{:02}", year, day);
+    insert_line(
+        &root.join("Cargo.toml"),
+        &format!("\"{dir}\"", dir = dir),
+        Some("]"),
+        &format!("    \"{dir}\",", dir = dir),
+    )?;
+    insert_line(
+        &root.join("aoc").join("Cargo.toml"),
+        &format!("{name} = ", name = name),
+        None,
+        &format!("{name} = {{ path = \"../{dir}\" }}", name = name, dir = dir),
+    )?;
+    insert_line(
+        &root.join("aoc").join("src").join("days.rs"),
+        &format!("{name}::", name = name),
+        Some("];"),
+        &format!(
+            "    Day::new::<{name}::Puzzle>({year}, {day}),",
+            name = name,
+            year = year,
+            day = day
+        ),
+    )
+}
+
+/// Creates the day's crate in `<year>/day_XX` of the workspace at `root` from
+/// `template` and wires it into the workspace. An existing day is left alone,
+/// unless `force` is given: then only the missing pieces are added, and existing
+/// source files are still never overwritten.
+pub fn new_day(
+    root: &Path,
+    year: u32,
+    day: u8,
+    template: &Template,
+    force: bool,
+) -> Result<(), HelperError> {
+    let dir = root.join(crate_dir(year, day));
+    if dir.exists() && !force {
+        return Err(HelperError::AlreadyExists(dir));
+    }
+    register_in_workspace(root, year, day)?;
+    fs::create_dir_all(dir.join("src")).map_err(|source| HelperError::Io {
+        path: dir.join("src"),
+        source,
+    })?;
+    if !dir.join("Cargo.toml").exists() {
+        write(
+            &dir.join("Cargo.toml"),
+            &format!(
+                "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"{edition}\"\n\n[dependencies]\n",
+                name = crate_name(year, day),
+                edition = EDITION
+            ),
+        )?;
+    }
+    add_dependency(
+        root,
+        year,
+        day,
+        "aoc_common = { path = \"../../aoc_common\" }",
+    )?;
+    for dependency in &template.dependencies {
+        add_dependency(root, year, day, dependency)?;
+    }
+    copy_template_to_main(root, year, day, &template.render(year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::template;
+    use std::env;
+
+    #[test]
+    fn scaffolds_a_day_into_the_workspace() {
+        let root = env::temp_dir().join(format!("aoc_helpers_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc").join("Cargo.toml"), "[dependencies]\n").unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("days.rs"),
+            "pub const DAYS: &[Day] = &[\n];\n",
+        )
+        .unwrap();
+
+        let template = template::find(template::DEFAULT_TEMPLATE).unwrap();
+        new_day(&root, 2021, 3, &template, false).unwrap();
+
+        let workspace = read(&root.join("Cargo.toml")).unwrap();
+        let days = read(&root.join("aoc").join("src").join("days.rs")).unwrap();
+        let manifest = read(&root.join("2021").join("day_03").join("Cargo.toml")).unwrap();
+        let lib_exists = root.join("2021/day_03/src/lib.rs").exists();
+        let again = new_day(&root, 2021, 3, &template, false);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            workspace,
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2021/day_03\",\n]\n"
+        );
+        assert_eq!(
+            days,
+            "pub const DAYS: &[Day] = &[\n    Day::new::<day_2021_03::Puzzle>(2021, 3),\n];\n"
+        );
+        assert!(manifest.contains("name = \"day_2021_03\""));
+        assert!(manifest.contains("edition = \"2021\""));
+        assert!(manifest.contains("aoc_common = { path = \"../../aoc_common\" }"));
+        assert!(lib_exists);
+        assert!(matches!(again, Err(HelperError::AlreadyExists(_))));
+    }
+}
@@ -0,0 +1,263 @@
+//! The session cookie the site knows the user by. It comes from
+//! `AOC_SESSION_COOKIE` when that is set, otherwise from `credentials.toml` in the
+//! config directory, which holds a default session and, for years played on
+//! another account, one per year:
+//!
+//! ```toml
+//! session = "53616c7465645f5f..."
+//!
+//! [2021]
+//! session = "8b1a9953c4611296..."
+//! ```
+//!
+//! Either way the value is the hex string from the browser's `session` cookie,
+//! with or without the `session=` in front.
+
+use crate::config::config_dir;
+use crate::error::HelperError;
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+pub const SESSION_VAR: &str = "AOC_SESSION_COOKIE";
+pub const CREDENTIALS_FILE: &str = "credentials.toml";
+
+/// What the site says instead of an input when the cookie is missing or stale.
+const LOGGED_OUT: &str = "Please log in";
+
+/// The link every page shows to someone logged out, public pages included.
+const LOG_IN_LINK: &str = "[Log In]";
+
+/// Whether `body` is the site asking to log in rather than the page asked for.
+pub fn is_logged_out_page(body: &str) -> bool {
+    body.contains(LOGGED_OUT)
+}
+
+/// A session cookie and where it came from, for error messages.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Session {
+    value: String,
+    pub source: String,
+}
+
+impl Session {
+    /// Checks that `raw` looks like a session cookie. A leading `session=` and
+    /// surrounding whitespace are dropped.
+    pub fn parse(raw: &str, source: &str) -> Result<Session, HelperError> {
+        let value = raw.trim();
+        let value = value.strip_prefix("session=").unwrap_or(value).trim();
+        let problem = if value.is_empty() {
+            Some("it is empty")
+        } else if !value.chars().all(|c| c.is_ascii_hexdigit()) {
+            Some("a session cookie is only hex digits")
+        } else {
+            None
+        };
+        if let Some(problem) = problem {
+            return Err(HelperError::BadCookie {
+                source: source.to_string(),
+                message: problem.to_string(),
+            });
+        }
+        Ok(Session {
+            value: value.to_string(),
+            source: source.to_string(),
+        })
+    }
+
+    /// The value for a `Cookie` header.
+    pub fn header(&self) -> String {
+        format!("session={}", self.value)
+    }
+}
+
+impl fmt::Display for Session {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the session cookie from {}", self.source)
+    }
+}
+
+/// The sessions in a credentials file.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Credentials {
+    default: Option<String>,
+    years: BTreeMap<u32, String>,
+}
+
+impl Credentials {
+    pub fn parse(text: &str, path: &Path) -> Result<Credentials, HelperError> {
+        let bad = |message: String| HelperError::BadCredentials {
+            path: path.to_path_buf(),
+            message,
+        };
+        let table: toml::Table = text.parse().map_err(|e| bad(format!("{}", e)))?;
+        let mut credentials = Credentials::default();
+        for (key, value) in table {
+            match (key.as_str(), value) {
+                ("session", toml::Value::String(session)) => credentials.default = Some(session),
+                (year, toml::Value::Table(mut profile)) => {
+                    let year = year
+                        .parse()
+                        .map_err(|_| bad(format!("[{}] is not a year", year)))?;
+                    match profile.remove("session") {
+                        Some(toml::Value::String(session)) => {
+                            credentials.years.insert(year, session);
+                        }
+                        _ => return Err(bad(format!("[{}] has no session", year))),
+                    }
+                }
+                (key, _) => return Err(bad(format!("unexpected `{}`", key))),
+            }
+        }
+        Ok(credentials)
+    }
+
+    /// The file at `path`, or no sessions at all when there is no such file.
+    pub fn load(path: &Path) -> Result<Credentials, HelperError> {
+        match fs::read_to_string(path) {
+            Ok(text) => Credentials::parse(&text, path),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Credentials::default()),
+            Err(source) => Err(HelperError::Io {
+                path: path.to_path_buf(),
+                source,
+            }),
+        }
+    }
+
+    /// The session for `year`: its own profile if it has one, otherwise the default.
+    pub fn session(&self, year: u32, path: &Path) -> Option<Result<Session, HelperError>> {
+        if let Some(value) = self.years.get(&year) {
+            let source = format!("[{}] in {}", year, path.display());
+            return Some(Session::parse(value, &source));
+        }
+        self.default
+            .as_ref()
+            .map(|value| Session::parse(value, &path.display().to_string()))
+    }
+}
+
+fn credentials_path() -> Option<PathBuf> {
+    config_dir().map(|dir| dir.join(CREDENTIALS_FILE))
+}
+
+/// The session to use for `year`, from the environment or the credentials file.
+pub fn find(year: u32) -> Result<Session, HelperError> {
+    if let Ok(value) = env::var(SESSION_VAR) {
+        return Session::parse(&value, SESSION_VAR);
+    }
+    let path = credentials_path();
+    if let Some(path) = &path {
+        if let Some(session) = Credentials::load(path)?.session(year, path) {
+            return session;
+        }
+    }
+    Err(HelperError::MissingCookie { year, path })
+}
+
+/// Asks the site for the settings page, which only a logged in user gets. Being
+/// sent elsewhere, or getting a page with a link to log in, means the session is
+/// no good.
+#[tokio::main]
+pub async fn check(base_url: &str, session: &Session) -> Result<(), HelperError> {
+    let url = format!("{}/settings", base_url.trim_end_matches('/'));
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()?;
+    let response = client
+        .get(&url)
+        .header("Cookie", session.header())
+        .send()
+        .await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if status.is_redirection() || is_logged_out_page(&body) || body.contains(LOG_IN_LINK) {
+        return Err(HelperError::LoggedOut(session.clone()));
+    }
+    if !status.is_success() {
+        return Err(HelperError::BadResponse {
+            url,
+            status: status.as_u16(),
+            body,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::mock_server;
+
+    fn session(raw: &str) -> Session {
+        Session::parse(raw, "test").unwrap()
+    }
+
+    #[test]
+    fn takes_cookies_with_or_without_the_name() {
+        assert_eq!(session("53616c74").header(), "session=53616c74");
+        assert_eq!(session(" session=53616c74\n").header(), "session=53616c74");
+        for bad in ["", "session=", "none", "53616c74; other=1"] {
+            match Session::parse(bad, "test") {
+                Err(HelperError::BadCookie { .. }) => {}
+                other => panic!("expected {:?} to be refused, got {:?}", bad, other),
+            }
+        }
+    }
+
+    #[test]
+    fn picks_the_years_profile_or_the_default() {
+        let path = Path::new("credentials.toml");
+        let credentials =
+            Credentials::parse("session = \"aa\"\n\n[2021]\nsession = \"bb\"\n", path).unwrap();
+        let year = credentials.session(2021, path).unwrap().unwrap();
+        assert_eq!(year.header(), "session=bb");
+        assert_eq!(year.source, "[2021] in credentials.toml");
+        assert_eq!(
+            credentials.session(2022, path).unwrap().unwrap().header(),
+            "session=aa"
+        );
+        assert!(Credentials::parse("[2021]\nsession = \"bb\"\n", path)
+            .unwrap()
+            .session(2022, path)
+            .is_none());
+        for bad in [
+            "[tuesday]\nsession = \"aa\"\n",
+            "[2021]\n",
+            "cookie = \"aa\"\n",
+        ] {
+            assert!(Credentials::parse(bad, path).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn accepts_a_live_session() {
+        let (url, request) = mock_server(200, "<h2>Settings</h2>".to_string());
+        check(&url, &session("abc123")).unwrap();
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("GET /settings "));
+        assert!(request.contains("session=abc123"));
+    }
+
+    #[test]
+    fn notices_a_stale_session() {
+        for (status, body) in [
+            (302, ""),
+            (200, "<a href=\"/auth/login\">[Log In]</a>"),
+            (400, "Please log in to get your puzzle input."),
+        ] {
+            let (url, _request) = mock_server(status, body.to_string());
+            match check(&url, &session("abc123")) {
+                Err(HelperError::LoggedOut(_)) => {}
+                other => panic!("expected {} {:?} to log out, got {:?}", status, body, other),
+            }
+        }
+        let (url, _request) = mock_server(500, "oops".to_string());
+        assert!(matches!(
+            check(&url, &session("abc123")),
+            Err(HelperError::BadResponse { status: 500, .. })
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+use crate::submit::Ledger;
+use aoc_common::cache::{Cache, Entry};
+use aoc_common::crate_dir;
+use aoc_common::regression;
+
+fn mark(present: bool) -> &'static str {
+    if present {
+        "yes"
+    } else {
+        "-"
+    }
+}
+
+/// One row per day that has a crate: whether its input is cached, whether it has an
+/// example, how many answers are recorded in `answers.toml` and how many answers the
+/// site rejected.
+pub fn print_status(year: u32, ledger: &Ledger) {
+    let cache = Cache::in_workspace(".");
+    println!("day | input | example | answers | rejected");
+    println!("----+-------+---------+---------+---------");
+    for day in 1..=25 {
+        let dir = crate_dir(year, day);
+        if !dir.is_dir() {
+            continue;
+        }
+        let input = cache.path(year, day, Entry::Input);
+        let answers = match regression::load(&dir, &input) {
+            Ok(fixtures) => fixtures
+                .iter()
+                .map(|fixture| fixture.answers.len())
+                .sum::<usize>()
+                .to_string(),
+            Err(_) => "invalid".to_string(),
+        };
+        println!(
+            "{:>3} | {:<5} | {:<7} | {:<7} | {}",
+            day,
+            mark(input.exists()),
+            mark(dir.join("example").exists() || dir.join("test").exists()),
+            answers,
+            ledger.rejected_count(year, day)
+        );
+    }
+}
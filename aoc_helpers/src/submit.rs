@@ -0,0 +1,418 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Where rejected answers are kept, relative to the workspace root.
+pub const LEDGER: &str = "rejected_answers";
+
+/// What the answer page said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited(Duration),
+    AlreadySolved,
+}
+
+impl Verdict {
+    fn is_rejection(&self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::RateLimited(_) => "rate limited",
+            Verdict::AlreadySolved => "already solved",
+        }
+    }
+
+    fn from_label(label: &str) -> Option<Verdict> {
+        match label {
+            "wrong" => Some(Verdict::Wrong),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::RateLimited(wait) => write!(f, "rate limited, wait {}s", wait.as_secs()),
+            verdict => write!(f, "{}", verdict.label()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    Http(reqwest::Error),
+    Ledger {
+        path: PathBuf,
+        source: io::Error,
+    },
+    /// The answer page did not say anything we recognise.
+    UnknownResponse(String),
+    /// The ledger already rules the answer out, so it was not sent.
+    AlreadyRejected {
+        answer: String,
+        verdict: Verdict,
+    },
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Http(e) => write!(f, "could not submit: {}", e),
+            SubmitError::Ledger { path, source } => {
+                write!(f, "could not use ledger {}: {}", path.display(), source)
+            }
+            SubmitError::UnknownResponse(text) => write!(f, "unexpected response: {}", text),
+            SubmitError::AlreadyRejected { answer, verdict } => {
+                write!(
+                    f,
+                    "{} was already rejected as {}, not sending it",
+                    answer, verdict
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+impl From<reqwest::Error> for SubmitError {
+    fn from(e: reqwest::Error) -> SubmitError {
+        SubmitError::Http(e)
+    }
+}
+
+/// The text of the page's `<article>`, where the verdict is, without tags.
+fn article_text(html: &str) -> String {
+    let article = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+/// Reads a wait like `1m 37s` out of "You have 1m 37s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (number, unit) = token.split_at(token.len() - 1);
+        let number = number.parse::<u64>().ok()?;
+        seconds += match unit {
+            "h" => number * 3600,
+            "m" => number * 60,
+            "s" => number,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Works out the verdict from the HTML the answer endpoint sends back.
+pub fn parse_response(html: &str) -> Result<Verdict, SubmitError> {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        if text.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Wrong)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        parse_wait(&text)
+            .map(Verdict::RateLimited)
+            .ok_or(SubmitError::UnknownResponse(text))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else {
+        Err(SubmitError::UnknownResponse(text))
+    }
+}
+
+/// One rejected answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rejection {
+    pub year: u32,
+    pub day: u8,
+    pub part: u8,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+/// Every answer the site has turned down, one tab separated line each, so the same
+/// wrong value is never sent twice.
+pub struct Ledger {
+    path: PathBuf,
+    rejections: Vec<Rejection>,
+}
+
+impl Ledger {
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Ledger, SubmitError> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(source) => return Err(SubmitError::Ledger { path, source }),
+        };
+        let rejections = contents
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.splitn(5, '\t').collect();
+                match fields[..] {
+                    [year, day, part, verdict, answer] => Some(Rejection {
+                        year: year.parse().ok()?,
+                        day: day.parse().ok()?,
+                        part: part.parse().ok()?,
+                        verdict: Verdict::from_label(verdict)?,
+                        answer: answer.to_string(),
+                    }),
+                    _ => None,
+                }
+            })
+            .collect();
+        Ok(Ledger { path, rejections })
+    }
+
+    /// The earlier rejection that rules `answer` out, if any. A numeric answer is also
+    /// ruled out when it is on the wrong side of an answer that was too high or too low.
+    pub fn rejects(&self, year: u32, day: u8, part: u8, answer: &str) -> Option<&Rejection> {
+        let number = answer.parse::<i64>().ok();
+        self.rejections
+            .iter()
+            .filter(|r| r.year == year && r.day == day && r.part == part)
+            .find(|r| {
+                if r.answer == answer {
+                    return true;
+                }
+                match (number, r.answer.parse::<i64>().ok(), &r.verdict) {
+                    (Some(n), Some(bound), Verdict::TooHigh) => n >= bound,
+                    (Some(n), Some(bound), Verdict::TooLow) => n <= bound,
+                    _ => false,
+                }
+            })
+    }
+
+    pub fn rejected_count(&self, year: u32, day: u8) -> usize {
+        self.rejections
+            .iter()
+            .filter(|r| r.year == year && r.day == day)
+            .count()
+    }
+
+    pub fn record(&mut self, rejection: Rejection) -> Result<(), SubmitError> {
+        self.rejections.push(rejection);
+        let contents: String = self
+            .rejections
+            .iter()
+            .map(|r| {
+                format!(
+                    "{}\t{}\t{}\t{}\t{}\n",
+                    r.year,
+                    r.day,
+                    r.part,
+                    r.verdict.label(),
+                    r.answer
+                )
+            })
+            .collect();
+        fs::write(&self.path, contents).map_err(|source| SubmitError::Ledger {
+            path: self.path.clone(),
+            source,
+        })
+    }
+}
+
+#[tokio::main]
+async fn post_answer(
+    base_url: &str,
+    cookie: &str,
+    year: u32,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<String, reqwest::Error> {
+    let client = reqwest::Client::new();
+    client
+        .post(format!(
+            "{base_url}/{year}/day/{day}/answer",
+            base_url = base_url.trim_end_matches('/'),
+            year = year,
+            day = day
+        ))
+        .header("Cookie", cookie)
+        .form(&[("level", part.to_string()), ("answer", answer.to_string())])
+        .send()
+        .await?
+        .text()
+        .await
+}
+
+/// Sends `answer` unless the ledger already rules it out, and records it there if
+/// the site rejects it.
+pub fn submit(
+    ledger: &mut Ledger,
+    base_url: &str,
+    cookie: &str,
+    year: u32,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> Result<Verdict, SubmitError> {
+    if let Some(rejection) = ledger.rejects(year, day, part, answer) {
+        return Err(SubmitError::AlreadyRejected {
+            answer: answer.to_string(),
+            verdict: rejection.verdict.clone(),
+        });
+    }
+    let html = post_answer(base_url, cookie, year, day, part, answer)?;
+    let verdict = parse_response(&html)?;
+    if verdict.is_rejection() {
+        ledger.record(Rejection {
+            year,
+            day,
+            part,
+            verdict: verdict.clone(),
+            answer: answer.to_string(),
+        })?;
+    }
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::mock_server;
+    use std::env;
+
+    fn page(article: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            article
+        )
+    }
+
+    fn temp_ledger(name: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("aoc_helpers_{}_{}", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    #[test]
+    fn parses_every_verdict() {
+        let cases = [
+            ("That's the right answer! You are one gold star closer.", Verdict::Correct),
+            ("That's not the right answer. If you're stuck, make sure...", Verdict::Wrong),
+            ("That's not the right answer; your answer is too high.", Verdict::TooHigh),
+            ("That's not the right answer; your answer is too low.", Verdict::TooLow),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 1m 37s left to wait.",
+                Verdict::RateLimited(Duration::from_secs(97)),
+            ),
+            (
+                "You don't seem to be solving the right level. Did you already complete it?",
+                Verdict::AlreadySolved,
+            ),
+        ];
+        for (article, verdict) in cases {
+            assert_eq!(parse_response(&page(article)).unwrap(), verdict);
+        }
+        assert!(parse_response(&page("Something else")).is_err());
+    }
+
+    #[test]
+    fn ledger_rules_out_repeats_and_bounds() {
+        let path = temp_ledger("bounds");
+        let mut ledger = Ledger::open(&path).unwrap();
+        for (verdict, answer) in [
+            (Verdict::TooHigh, "500"),
+            (Verdict::TooLow, "100"),
+            (Verdict::Wrong, "abc"),
+        ] {
+            ledger
+                .record(Rejection {
+                    year: 2022,
+                    day: 1,
+                    part: 1,
+                    verdict,
+                    answer: answer.to_string(),
+                })
+                .unwrap();
+        }
+
+        let ledger = Ledger::open(&path).unwrap();
+        assert!(ledger.rejects(2022, 1, 1, "abc").is_some());
+        assert!(ledger.rejects(2022, 1, 1, "600").is_some());
+        assert!(ledger.rejects(2022, 1, 1, "50").is_some());
+        assert!(ledger.rejects(2022, 1, 1, "300").is_none());
+        assert!(ledger.rejects(2022, 1, 2, "abc").is_none());
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn submits_to_mock_server_and_records_rejection() {
+        let path = temp_ledger("mock");
+        let mut ledger = Ledger::open(&path).unwrap();
+        let (url, request) = mock_server(
+            200,
+            page("That's not the right answer; your answer is too low."),
+        );
+
+        let verdict = submit(&mut ledger, &url, "session=abc", 2022, 7, 2, "42").unwrap();
+        assert_eq!(verdict, Verdict::TooLow);
+
+        let request = request.recv().unwrap();
+        assert!(request.starts_with("POST /2022/day/7/answer "));
+        assert!(request.contains("session=abc"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        // Lower answers are refused without another request.
+        match submit(&mut ledger, &url, "session=abc", 2022, 7, 2, "41") {
+            Err(SubmitError::AlreadyRejected { verdict, .. }) => {
+                assert_eq!(verdict, Verdict::TooLow)
+            }
+            other => panic!("expected the ledger to refuse, got {:?}", other),
+        }
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn rate_limit_is_not_recorded() {
+        let path = temp_ledger("rate");
+        let mut ledger = Ledger::open(&path).unwrap();
+        let (url, _request) = mock_server(200, page(
+            "You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 12s left to wait.",
+        ));
+
+        let verdict = submit(&mut ledger, &url, "", 2022, 7, 1, "42").unwrap();
+        assert_eq!(verdict, Verdict::RateLimited(Duration::from_secs(12)));
+        assert!(ledger.rejects(2022, 7, 1, "42").is_none());
+    }
+}
@@ -0,0 +1,231 @@
+//! The starting `lib.rs` for a new day. A few flavours are built in, and more can be
+//! added as `templates/<name>.rs` in the config directory, with any dependencies
+//! they need in a `[dependencies]` table in `templates/<name>.toml`. A user template
+//! with the name of a built-in one replaces it.
+//!
+//! `{day}`, `{year}` and `{crate_name}` are replaced in the template; every other
+//! brace is left as it is.
+
+use crate::config::config_dir;
+use crate::error::HelperError;
+use aoc_common::crate_name;
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_TEMPLATE: &str = "lines";
+
+const BUILT_IN: [(&str, &str, &[&str]); 4] = [
+    ("lines", include_str!("../templates/lines.rs"), &[]),
+    ("grid", include_str!("../templates/grid.rs"), &[]),
+    ("groups", include_str!("../templates/groups.rs"), &[]),
+    (
+        "records",
+        include_str!("../templates/records.rs"),
+        &["regex = \"1\""],
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    pub name: String,
+    pub source: String,
+    /// Lines to add under `[dependencies]` in the day's manifest.
+    pub dependencies: Vec<String>,
+}
+
+impl Template {
+    /// The template's source with the placeholders filled in for `day` of `year`.
+    pub fn render(&self, year: u32, day: u8) -> String {
+        self.source
+            .replace("{day}", &day.to_string())
+            .replace("{year}", &year.to_string())
+            .replace("{crate_name}", &crate_name(year, day))
+    }
+}
+
+fn built_in() -> Vec<Template> {
+    BUILT_IN
+        .iter()
+        .map(|(name, source, dependencies)| Template {
+            name: name.to_string(),
+            source: source.to_string(),
+            dependencies: dependencies.iter().map(|d| d.to_string()).collect(),
+        })
+        .collect()
+}
+
+fn read_user_template(rust_file: &Path) -> Result<Template, HelperError> {
+    let io_error = |path: &Path| {
+        let path = path.to_path_buf();
+        move |source| HelperError::Io { path, source }
+    };
+    let source = fs::read_to_string(rust_file).map_err(io_error(rust_file))?;
+    let manifest = rust_file.with_extension("toml");
+    let mut dependencies = vec![];
+    if manifest.exists() {
+        let table = fs::read_to_string(&manifest)
+            .map_err(io_error(&manifest))?
+            .parse::<toml::Table>()
+            .map_err(|e| HelperError::BadTemplate {
+                path: manifest.clone(),
+                message: e.to_string(),
+            })?;
+        if let Some(deps) = table.get("dependencies").and_then(|d| d.as_table()) {
+            dependencies = deps
+                .iter()
+                .map(|(name, version)| format!("{} = {}", name, version))
+                .collect();
+        }
+    }
+    Ok(Template {
+        name: rust_file
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default(),
+        source,
+        dependencies,
+    })
+}
+
+/// The templates in `dir`, which need not exist.
+fn user_templates(dir: &Path) -> Result<Vec<Template>, HelperError> {
+    if !dir.is_dir() {
+        return Ok(vec![]);
+    }
+    let entries = fs::read_dir(dir).map_err(|source| HelperError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    let mut templates = vec![];
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.extension().is_some_and(|extension| extension == "rs") {
+            templates.push(read_user_template(&path)?);
+        }
+    }
+    Ok(templates)
+}
+
+/// Every template there is, sorted by name, with user templates overriding the
+/// built-in ones.
+pub fn available() -> Result<Vec<Template>, HelperError> {
+    let user = match config_dir() {
+        Some(dir) => user_templates(&dir.join("templates"))?,
+        None => vec![],
+    };
+    let mut templates = built_in();
+    templates.retain(|template| !user.iter().any(|u| u.name == template.name));
+    templates.extend(user);
+    templates.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(templates)
+}
+
+pub fn find(name: &str) -> Result<Template, HelperError> {
+    let templates = available()?;
+    let names = templates.iter().map(|t| t.name.clone()).collect();
+    templates
+        .into_iter()
+        .find(|template| template.name == name)
+        .ok_or_else(|| HelperError::UnknownTemplate {
+            name: name.to_string(),
+            available: names,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::path::PathBuf;
+    use std::process::Command;
+
+    #[test]
+    fn fills_in_only_the_placeholders() {
+        let template = Template {
+            name: "t".to_string(),
+            source: "// {crate_name} {year}/{day}\nfn f() { format!(\"{x}\") }\n".to_string(),
+            dependencies: vec![],
+        };
+        assert_eq!(
+            template.render(2021, 7),
+            "// day_2021_07 2021/7\nfn f() { format!(\"{x}\") }\n"
+        );
+    }
+
+    #[test]
+    fn reads_user_templates_with_their_dependencies() {
+        let dir = env::temp_dir().join(format!("aoc_user_templates_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("mine.rs"), "// {day}\n").unwrap();
+        fs::write(
+            dir.join("mine.toml"),
+            "[dependencies]\nitertools = \"0.10\"\n",
+        )
+        .unwrap();
+        fs::write(dir.join("notes.txt"), "not a template").unwrap();
+        let templates = user_templates(&dir).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            templates,
+            vec![Template {
+                name: "mine".to_string(),
+                source: "// {day}\n".to_string(),
+                dependencies: vec!["itertools = \"0.10\"".to_string()],
+            }]
+        );
+    }
+
+    /// Builds a throwaway workspace with a crate per built-in template and runs
+    /// their tests, so a template that stops compiling is caught here rather than
+    /// on the morning of a puzzle.
+    #[test]
+    fn built_in_templates_compile_and_pass() {
+        let repo = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..");
+        let root = env::temp_dir().join(format!("aoc_templates_{}", std::process::id()));
+        let templates = built_in();
+        let mut members = vec![];
+        for (day, template) in (1..).zip(&templates) {
+            let name = crate_name(2022, day);
+            fs::create_dir_all(root.join(&name).join("src")).unwrap();
+            fs::write(
+                root.join(&name).join("Cargo.toml"),
+                format!(
+                    "[package]\nname = \"{name}\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[dependencies]\naoc_common = {{ path = {common:?} }}\n{deps}",
+                    name = name,
+                    common = repo.join("aoc_common").canonicalize().unwrap(),
+                    deps = template.dependencies.join("\n")
+                ),
+            )
+            .unwrap();
+            fs::write(
+                root.join(&name).join("src").join("lib.rs"),
+                template.render(2022, day),
+            )
+            .unwrap();
+            members.push(format!("{:?}", name));
+        }
+        fs::write(
+            root.join("Cargo.toml"),
+            format!(
+                "[workspace]\nresolver = \"2\"\nmembers = [{}]\n",
+                members.join(", ")
+            ),
+        )
+        .unwrap();
+        // The repository's lock file keeps the build to crates it already uses.
+        fs::copy(repo.join("Cargo.lock"), root.join("Cargo.lock")).unwrap();
+
+        let output = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()))
+            .args(["test", "--quiet", "--workspace"])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", root.join("target"))
+            .output()
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
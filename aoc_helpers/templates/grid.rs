@@ -0,0 +1,36 @@
+//! {crate_name}: Advent of Code {year}, day {day}.
+
+use aoc_common::{Answer, AocError, Grid, Result, Solution};
+
+pub struct Puzzle {
+    pub grid: Grid<char>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle> {
+        Ok(Puzzle {
+            grid: Grid::parse(input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_example() {
+        let puzzle = Puzzle::parse("#..\n.#.\n").unwrap();
+        assert_eq!(puzzle.grid.height(), 2);
+        assert_eq!(puzzle.grid.row(1), ['.', '#', '.']);
+        assert!(Puzzle::parse("#..\n.#\n").is_err());
+    }
+}
@@ -0,0 +1,42 @@
+//! {crate_name}: Advent of Code {year}, day {day}.
+
+use aoc_common::{Answer, AocError, Result, Solution};
+
+pub struct Puzzle {
+    /// The lines of each blank line separated group.
+    pub groups: Vec<Vec<String>>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle> {
+        let mut groups = vec![vec![]];
+        for line in input.lines() {
+            if line.trim().is_empty() {
+                groups.push(vec![]);
+            } else {
+                groups.last_mut().unwrap().push(line.to_string());
+            }
+        }
+        groups.retain(|group| !group.is_empty());
+        Ok(Puzzle { groups })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_example() {
+        let puzzle = Puzzle::parse("1\n2\n\n3\n").unwrap();
+        assert_eq!(puzzle.groups, vec![vec!["1", "2"], vec!["3"]]);
+    }
+}
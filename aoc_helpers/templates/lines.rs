@@ -0,0 +1,34 @@
+//! {crate_name}: Advent of Code {year}, day {day}.
+
+use aoc_common::{Answer, AocError, Result, Solution};
+
+pub struct Puzzle {
+    pub lines: Vec<String>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle> {
+        Ok(Puzzle {
+            lines: input.lines().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_example() {
+        let puzzle = Puzzle::parse("first\nsecond\n").unwrap();
+        assert_eq!(puzzle.lines, vec!["first", "second"]);
+    }
+}
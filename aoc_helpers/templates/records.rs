@@ -0,0 +1,62 @@
+//! {crate_name}: Advent of Code {year}, day {day}.
+
+use aoc_common::{Answer, AocError, Result, Solution};
+use regex::Regex;
+
+/// One line of the input.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Record {
+    pub name: String,
+    pub value: i64,
+}
+
+pub struct Puzzle {
+    pub records: Vec<Record>,
+}
+
+impl Solution for Puzzle {
+    fn parse(input: &str) -> Result<Puzzle> {
+        let record = Regex::new(r"^(\w+): (-?\d+)$").unwrap();
+        let records = input
+            .lines()
+            .map(|line| {
+                let captures = record
+                    .captures(line)
+                    .ok_or_else(|| AocError::parse(format!("bad record: {}", line)))?;
+                Ok(Record {
+                    name: captures[1].to_string(),
+                    value: captures[2]
+                        .parse()
+                        .map_err(|_| AocError::parse(format!("bad value: {}", line)))?,
+                })
+            })
+            .collect::<Result<Vec<Record>>>()?;
+        Ok(Puzzle { records })
+    }
+
+    fn part1(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+
+    fn part2(&self) -> Result<Answer> {
+        Err(AocError::Unsolved)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_example() {
+        let puzzle = Puzzle::parse("a: 1\nb: -2\n").unwrap();
+        assert_eq!(
+            puzzle.records[1],
+            Record {
+                name: "b".to_string(),
+                value: -2
+            }
+        );
+        assert!(Puzzle::parse("a = 1\n").is_err());
+    }
+}